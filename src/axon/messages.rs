@@ -0,0 +1,87 @@
+//! Messages which are defined in `core-mempool` and `core-consensus` of Axon.
+//!
+//! Those crates drag the whole storage and executor of Axon in, so the
+//! definitions are mirrored here, and they should keep the same encoding.
+
+use rlp_derive::{RlpDecodable, RlpEncodable};
+
+use axon_protocol::{
+    traits::MessageCodec,
+    types::{Bytes, SignedTransaction, H256},
+    ProtocolResult,
+};
+
+//
+// Mempool
+//
+
+#[derive(Clone, Debug, PartialEq, Eq, RlpEncodable, RlpDecodable)]
+pub struct MsgPullTxs {
+    pub height: Option<u64>,
+    pub hashes: Vec<H256>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, RlpEncodable, RlpDecodable)]
+pub struct MsgPushTxs {
+    pub sig_txs: Vec<SignedTransaction>,
+}
+
+//
+// Consensus
+//
+
+macro_rules! define_overlord_message {
+    ($name:ident) => {
+        /// An encoded Overlord message, it's transmitted as-is.
+        #[derive(Clone, Debug, PartialEq, Eq)]
+        pub struct $name(pub Vec<u8>);
+
+        impl MessageCodec for $name {
+            fn encode_msg(&mut self) -> ProtocolResult<Bytes> {
+                Ok(Bytes::from(self.0.clone()))
+            }
+
+            fn decode_msg(bytes: Bytes) -> ProtocolResult<Self> {
+                Ok(Self(bytes.to_vec()))
+            }
+        }
+    };
+}
+
+define_overlord_message!(Proposal);
+define_overlord_message!(Vote);
+define_overlord_message!(QC);
+define_overlord_message!(Choke);
+
+//
+// Storage
+//
+
+#[derive(Clone, Debug, PartialEq, Eq, RlpEncodable, RlpDecodable)]
+pub struct PullTxsRequest {
+    pub height: u64,
+    pub inner: Vec<H256>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, RlpEncodable, RlpDecodable)]
+pub struct FixedSignedTxs {
+    pub inner: Vec<SignedTransaction>,
+}
+
+//
+// Others
+//
+
+/// Bytes which are transmitted as-is, without any encoding.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RawMessage(pub Bytes);
+
+impl MessageCodec for RawMessage {
+    fn encode_msg(&mut self) -> ProtocolResult<Bytes> {
+        Ok(self.0.clone())
+    }
+
+    fn decode_msg(bytes: Bytes) -> ProtocolResult<Self> {
+        Ok(Self(bytes))
+    }
+}
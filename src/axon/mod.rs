@@ -1,8 +1,11 @@
+mod messages;
+
 pub mod core {
     pub use axon_core_network as network;
 }
 pub mod common {
     pub use axon_common_config_parser as config_parser;
+    pub use axon_common_crypto as crypto;
 }
 pub use axon_protocol as protocol;
 
@@ -32,22 +35,21 @@ pub mod services {
             pub use axon_protocol::constants::endpoints::BROADCAST_HEIGHT;
         }
     }
-    /* TODO real messages
     pub mod messages {
         pub mod mempool {
-            pub use axon_core_mempool::MsgPullTxs;
+            pub use crate::axon::messages::{MsgPullTxs, MsgPushTxs};
             pub use axon_protocol::types::BatchSignedTxs;
         }
         pub mod consensus {
-            pub use axon_core_consensus::message::{Choke, Proposal, Vote, QC};
+            pub use crate::axon::messages::{Choke, Proposal, Vote, QC};
         }
         pub mod storage {
-            pub use axon_core_consensus::message::PullTxsRequest;
-            pub use axon_protocol::types::BlockNumber;
+            pub use crate::axon::messages::{FixedSignedTxs, PullTxsRequest};
+            pub use axon_protocol::types::{Block, BlockNumber, Header, Proof};
         }
         pub mod synchronization {
             pub use axon_protocol::types::BlockNumber;
         }
+        pub use crate::axon::messages::RawMessage;
     }
-    */
 }
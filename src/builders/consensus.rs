@@ -0,0 +1,293 @@
+use std::{error::Error as StdError, result::Result as StdResult};
+
+use rlp::Encodable;
+use rlp_derive::RlpEncodable;
+use serde::Deserialize;
+
+use overlord::{
+    types::{
+        AggregatedChoke, AggregatedSignature, AggregatedVote, Choke, PoLC, Proposal, SignedChoke,
+        SignedProposal, SignedVote, UpdateFrom, Vote, VoteType,
+    },
+    Codec,
+};
+
+use crate::{
+    axon::{
+        common::crypto::{BlsPrivateKey, HashValue, PrivateKey as _, Signature as _},
+        protocol::types::{Bytes, Hasher, Hex, Key256Bits},
+    },
+    result::{Error, Result},
+};
+
+#[derive(Clone, Copy, Debug, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum VoteKind {
+    Prevote,
+    Precommit,
+}
+
+/// Fields of an Overlord signed vote.
+///
+/// If the `signature` is omitted, the vote is signed by the configured BLS private key.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct VoteParams {
+    pub height: u64,
+    pub round: u64,
+    pub vote_type: VoteKind,
+    pub block_hash: Hex,
+    pub voter: Hex,
+    pub signature: Option<Hex>,
+}
+
+/// Fields of an Overlord aggregated vote, a.k.a. QC.
+///
+/// If the `signature` is omitted, the vote is signed by the configured BLS private key,
+/// as if the injector is the only voter.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct AggregatedVoteParams {
+    pub height: u64,
+    pub round: u64,
+    pub vote_type: VoteKind,
+    pub block_hash: Hex,
+    pub leader: Hex,
+    pub address_bitmap: Hex,
+    pub signature: Option<Hex>,
+}
+
+/// Fields of an Overlord signed proposal.
+///
+/// The `content` is the encoded block proposal, it's not checked, so it could be empty or
+/// oversized on purpose.
+/// If the `block_hash` is omitted, the hash of the `content` is used.
+/// If the `signature` is omitted, the proposal is signed by the configured BLS private key.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct ProposalParams {
+    pub height: u64,
+    pub round: u64,
+    pub proposer: Hex,
+    pub content: Hex,
+    pub block_hash: Option<Hex>,
+    pub lock: Option<LockParams>,
+    pub signature: Option<Hex>,
+}
+
+/// The lock of an Overlord proposal, a.k.a. PoLC.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct LockParams {
+    pub lock_round: u64,
+    pub lock_votes: AggregatedVoteParams,
+}
+
+/// Fields of an Overlord signed choke.
+///
+/// If the `signature` is omitted, the choke is signed by the configured BLS private key.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct ChokeParams {
+    pub height: u64,
+    pub round: u64,
+    pub address: Hex,
+    pub from: UpdateFromParams,
+    pub signature: Option<Hex>,
+}
+
+/// The reason why a node goes to the new round.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub enum UpdateFromParams {
+    PrevoteQc(AggregatedVoteParams),
+    PrecommitQc(AggregatedVoteParams),
+    ChokeQc(AggregatedChokeParams),
+}
+
+/// Fields of an Overlord aggregated choke.
+///
+/// If the `signature` is omitted, the choke is signed by the configured BLS private key,
+/// as if the injector is the only voter.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct AggregatedChokeParams {
+    pub height: u64,
+    pub round: u64,
+    pub voters: Vec<Hex>,
+    pub signature: Option<Hex>,
+}
+
+/// The part of a choke which is signed, same as the one in Overlord.
+#[derive(RlpEncodable)]
+struct HashChoke {
+    height: u64,
+    round: u64,
+}
+
+/// The block content in a proposal, it's transmitted as-is.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RawContent(pub Bytes);
+
+/// Builds Overlord messages and signs them as an Axon validator does.
+pub struct ConsensusBuilder {
+    private_key: Option<BlsPrivateKey>,
+}
+
+impl From<VoteKind> for VoteType {
+    fn from(kind: VoteKind) -> Self {
+        match kind {
+            VoteKind::Prevote => Self::Prevote,
+            VoteKind::Precommit => Self::Precommit,
+        }
+    }
+}
+
+impl ConsensusBuilder {
+    pub fn new(bls_key: Option<&Key256Bits>) -> Result<Self> {
+        let private_key = bls_key
+            .map(|key| BlsPrivateKey::try_from(&key[..]))
+            .transpose()
+            .map_err(|err| {
+                let errmsg = format!("failed to load the BLS private key since {err}");
+                Error::Consensus(errmsg)
+            })?;
+        Ok(Self { private_key })
+    }
+
+    pub fn signed_vote(&self, params: &VoteParams) -> Result<SignedVote> {
+        let vote = Vote {
+            height: params.height,
+            round: params.round,
+            vote_type: params.vote_type.into(),
+            block_hash: params.block_hash.as_bytes(),
+        };
+        let signature = self.sign_unless_specified(&vote, params.signature.as_ref())?;
+        Ok(SignedVote {
+            signature,
+            vote,
+            voter: params.voter.as_bytes(),
+        })
+    }
+
+    pub fn aggregated_vote(&self, params: &AggregatedVoteParams) -> Result<AggregatedVote> {
+        let vote = Vote {
+            height: params.height,
+            round: params.round,
+            vote_type: params.vote_type.into(),
+            block_hash: params.block_hash.as_bytes(),
+        };
+        let signature = self.sign_unless_specified(&vote, params.signature.as_ref())?;
+        Ok(AggregatedVote {
+            signature: AggregatedSignature {
+                signature,
+                address_bitmap: params.address_bitmap.as_bytes(),
+            },
+            vote_type: vote.vote_type,
+            height: vote.height,
+            round: vote.round,
+            block_hash: vote.block_hash,
+            leader: params.leader.as_bytes(),
+        })
+    }
+
+    pub fn signed_proposal(&self, params: &ProposalParams) -> Result<SignedProposal<RawContent>> {
+        let content = params.content.as_bytes();
+        let block_hash = params
+            .block_hash
+            .as_ref()
+            .map(Hex::as_bytes)
+            .unwrap_or_else(|| Bytes::from(Hasher::digest(&content).as_bytes().to_vec()));
+        let lock = params
+            .lock
+            .as_ref()
+            .map(|lock| {
+                self.aggregated_vote(&lock.lock_votes)
+                    .map(|lock_votes| PoLC {
+                        lock_round: lock.lock_round,
+                        lock_votes,
+                    })
+            })
+            .transpose()?;
+        let proposal = Proposal {
+            height: params.height,
+            round: params.round,
+            content: RawContent(content),
+            block_hash,
+            lock,
+            proposer: params.proposer.as_bytes(),
+        };
+        let signature = self.sign_unless_specified(&proposal, params.signature.as_ref())?;
+        Ok(SignedProposal {
+            signature,
+            proposal,
+        })
+    }
+
+    pub fn signed_choke(&self, params: &ChokeParams) -> Result<SignedChoke> {
+        let from = match &params.from {
+            UpdateFromParams::PrevoteQc(qc) => UpdateFrom::PrevoteQC(self.aggregated_vote(qc)?),
+            UpdateFromParams::PrecommitQc(qc) => UpdateFrom::PrecommitQC(self.aggregated_vote(qc)?),
+            UpdateFromParams::ChokeQc(qc) => UpdateFrom::ChokeQC(self.aggregated_choke(qc)?),
+        };
+        let choke = Choke {
+            height: params.height,
+            round: params.round,
+            from,
+        };
+        let hash_choke = HashChoke {
+            height: params.height,
+            round: params.round,
+        };
+        let signature = self.sign_unless_specified(&hash_choke, params.signature.as_ref())?;
+        Ok(SignedChoke {
+            signature,
+            choke,
+            address: params.address.as_bytes(),
+        })
+    }
+
+    fn aggregated_choke(&self, params: &AggregatedChokeParams) -> Result<AggregatedChoke> {
+        let hash_choke = HashChoke {
+            height: params.height,
+            round: params.round,
+        };
+        let signature = self.sign_unless_specified(&hash_choke, params.signature.as_ref())?;
+        Ok(AggregatedChoke {
+            height: params.height,
+            round: params.round,
+            signature,
+            voters: params.voters.iter().map(Hex::as_bytes).collect(),
+        })
+    }
+
+    fn sign_unless_specified<T: Encodable>(
+        &self,
+        msg: &T,
+        signature: Option<&Hex>,
+    ) -> Result<Bytes> {
+        if let Some(signature) = signature {
+            return Ok(signature.as_bytes());
+        }
+        let private_key = self.private_key.as_ref().ok_or_else(|| {
+            let errmsg = "no BLS private key is configured, the signature is required".to_owned();
+            Error::Consensus(errmsg)
+        })?;
+        let hash = Hasher::digest(rlp::encode(msg));
+        let hash_value = HashValue::try_from(hash.as_bytes()).map_err(|err| {
+            let errmsg = format!("failed to sign the message since {err}");
+            Error::Consensus(errmsg)
+        })?;
+        Ok(private_key.sign_message(&hash_value).to_bytes())
+    }
+}
+
+impl Codec for RawContent {
+    fn encode(&self) -> StdResult<Bytes, Box<dyn StdError + Send>> {
+        Ok(self.0.clone())
+    }
+
+    fn decode(data: Bytes) -> StdResult<Self, Box<dyn StdError + Send>> {
+        Ok(Self(data))
+    }
+}
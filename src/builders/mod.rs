@@ -0,0 +1,5 @@
+//! Builders to craft messages locally, so no external toolchain is required.
+
+pub mod consensus;
+pub mod nonce;
+pub mod transaction;
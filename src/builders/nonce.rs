@@ -0,0 +1,143 @@
+use std::{
+    collections::HashMap,
+    fs,
+    path::{Path, PathBuf},
+    process,
+    sync::Mutex,
+};
+
+use fs2::FileExt as _;
+
+use crate::{
+    axon::protocol::types::{H160, U256},
+    result::{Error, Result},
+};
+
+type Nonces = HashMap<H160, U256>;
+
+/// Tracks the nonces of the accounts which transactions are signed by the injector.
+///
+/// All nonces are persisted into a JSON file after each change.
+/// The file could be shared by processes, it's reloaded under an advisory file lock
+/// for each operation.
+pub struct NonceManager {
+    file_path: PathBuf,
+    lock_file: Mutex<fs::File>,
+}
+
+impl NonceManager {
+    /// Opens the nonces file, starts with nothing if the file doesn't exist.
+    pub fn load(file_path: &Path) -> Result<Self> {
+        if let Some(dir) = file_path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        let lock_file = fs::OpenOptions::new()
+            .create(true)
+            .write(true)
+            .open(file_path.with_extension("lock"))?;
+        let manager = Self {
+            file_path: file_path.to_owned(),
+            lock_file: Mutex::new(lock_file),
+        };
+        manager.read(|_| Ok(()))?;
+        Ok(manager)
+    }
+
+    /// Returns the nonce which will be used by the next transaction of the account.
+    pub fn get(&self, address: &H160) -> Result<U256> {
+        self.read(|nonces| Ok(nonces.get(address).copied().unwrap_or_default()))
+    }
+
+    /// Takes the nonce for a new transaction of the account, and increases the tracked nonce.
+    pub fn next(&self, address: &H160) -> Result<U256> {
+        self.update(|nonces| {
+            let nonce = nonces.get(address).copied().unwrap_or_default();
+            let next = nonce.checked_add(U256::one()).ok_or_else(|| {
+                let errmsg = format!("the nonce of account {address:#x} is exhausted");
+                Error::Nonce(errmsg)
+            })?;
+            nonces.insert(*address, next);
+            Ok(nonce)
+        })
+    }
+
+    /// Gives back a nonce taken by `next`, when its transaction is not sent.
+    ///
+    /// Nothing is changed if a later nonce has been taken, or the nonce has been changed.
+    pub fn release(&self, address: &H160, nonce: U256) -> Result<()> {
+        self.update(|nonces| {
+            if nonces.get(address) == Some(&nonce.saturating_add(U256::one())) {
+                nonces.insert(*address, nonce);
+            }
+            Ok(())
+        })
+    }
+
+    pub fn set(&self, address: &H160, nonce: U256) -> Result<()> {
+        self.update(|nonces| {
+            nonces.insert(*address, nonce);
+            Ok(())
+        })
+    }
+
+    /// Forgets the account, so its next nonce is zero.
+    pub fn reset(&self, address: &H160) -> Result<()> {
+        self.update(|nonces| {
+            nonces.remove(address);
+            Ok(())
+        })
+    }
+
+    fn read<T>(&self, func: impl FnOnce(&Nonces) -> Result<T>) -> Result<T> {
+        let lock_file = self
+            .lock_file
+            .lock()
+            .map_err(|err| Error::Nonce(err.to_string()))?;
+        lock_file.lock_shared()?;
+        let ret = self.load_nonces().and_then(|nonces| func(&nonces));
+        lock_file.unlock()?;
+        ret
+    }
+
+    fn update<T>(&self, func: impl FnOnce(&mut Nonces) -> Result<T>) -> Result<T> {
+        let lock_file = self
+            .lock_file
+            .lock()
+            .map_err(|err| Error::Nonce(err.to_string()))?;
+        lock_file.lock_exclusive()?;
+        let ret = self.load_nonces().and_then(|mut nonces| {
+            let value = func(&mut nonces)?;
+            self.persist(&nonces)?;
+            Ok(value)
+        });
+        lock_file.unlock()?;
+        ret
+    }
+
+    fn load_nonces(&self) -> Result<Nonces> {
+        if !self.file_path.exists() {
+            return Ok(HashMap::new());
+        }
+        let content = fs::read(&self.file_path)?;
+        serde_json::from_slice(&content).map_err(|err| {
+            let errmsg = format!(
+                "failed to parse nonces file {} since {err}",
+                self.file_path.display()
+            );
+            Error::Nonce(errmsg)
+        })
+    }
+
+    fn persist(&self, nonces: &Nonces) -> Result<()> {
+        let content = serde_json::to_vec_pretty(nonces).map_err(|err| {
+            let errmsg = format!("failed to encode nonces since {err}");
+            Error::Nonce(errmsg)
+        })?;
+        let tmp_path = self
+            .file_path
+            .with_extension(format!("{}.tmp", process::id()));
+        fs::write(&tmp_path, content)?;
+        fs::rename(&tmp_path, &self.file_path)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,181 @@
+use clap::ValueEnum;
+use serde::Deserialize;
+
+use crate::{
+    axon::{
+        common::crypto::{
+            Crypto as _, PrivateKey as _, Secp256k1Recoverable, Secp256k1RecoverablePrivateKey,
+            Signature as _, ToPublicKey as _, UncompressedPublicKey as _,
+        },
+        protocol::types::{
+            Bytes, Eip1559Transaction, Eip2930Transaction, Hasher, Hex, LegacyTransaction,
+            SignatureComponents, SignedTransaction, TransactionAction, UnsignedTransaction,
+            UnverifiedTransaction, H160, H256, U256,
+        },
+    },
+    result::{Error, Result},
+};
+
+use super::nonce::NonceManager;
+
+#[derive(Clone, Copy, Debug, Default, Deserialize, ValueEnum)]
+#[serde(rename_all = "lowercase")]
+pub enum TransactionType {
+    Legacy,
+    Eip2930,
+    #[default]
+    Eip1559,
+}
+
+/// Fields of a transaction to build.
+///
+/// For EIP-1559 transactions, the `gas_price` is used as the max fee per gas.
+///
+/// If the `nonce` is omitted, the next nonce of the sender in the local nonce tracker is used.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct TransactionParams {
+    #[serde(default, rename = "type")]
+    pub tx_type: TransactionType,
+    pub nonce: Option<U256>,
+    /// Create a contract if it's omitted.
+    pub to: Option<H160>,
+    #[serde(default)]
+    pub value: U256,
+    pub data: Option<Hex>,
+    pub gas_limit: U256,
+    pub gas_price: U256,
+    #[serde(default)]
+    pub max_priority_fee_per_gas: U256,
+}
+
+/// Builds transactions and signs them with a secp256k1 private key.
+pub struct TransactionBuilder {
+    chain_id: u64,
+    private_key: Bytes,
+    sender: H160,
+}
+
+impl TransactionBuilder {
+    pub fn new(chain_id: u64, private_key: H256) -> Result<Self> {
+        let sender = Secp256k1RecoverablePrivateKey::try_from(private_key.as_bytes())
+            .map(|key| {
+                let public = key.pub_key().to_uncompressed_bytes();
+                let hash = Hasher::digest(&public[1..]);
+                H160::from_slice(&hash.as_bytes()[12..])
+            })
+            .map_err(|err| {
+                let errmsg = format!("failed to load the private key since {err}");
+                Error::Axon(errmsg)
+            })?;
+        let private_key = Bytes::from(private_key.as_bytes().to_vec());
+        Ok(Self {
+            chain_id,
+            private_key,
+            sender,
+        })
+    }
+
+    /// The address of the private key.
+    pub fn sender(&self) -> H160 {
+        self.sender
+    }
+
+    /// Builds a signed transaction.
+    ///
+    /// The nonce tracker is not touched when the nonce is specified in the parameters.
+    /// Otherwise, the taken nonce is given back if the transaction fails to be built,
+    /// and it should be given back through `release` if the transaction fails to be sent.
+    pub fn build(
+        &self,
+        params: &TransactionParams,
+        nonces: &NonceManager,
+    ) -> Result<SignedTransaction> {
+        let Some(nonce) = params.nonce else {
+            let nonce = nonces.next(&self.sender)?;
+            return self.sign(params, nonce).map_err(|err| {
+                let _ignore = nonces.release(&self.sender, nonce).map_err(|err| {
+                    log::warn!("failed to release nonce {nonce} since {err}");
+                });
+                err
+            });
+        };
+        self.sign(params, nonce)
+    }
+
+    /// Gives back the nonce of a built transaction which is not sent,
+    /// if it was taken from the nonce tracker.
+    pub fn release(
+        &self,
+        params: &TransactionParams,
+        stx: &SignedTransaction,
+        nonces: &NonceManager,
+    ) -> Result<()> {
+        if params.nonce.is_some() {
+            return Ok(());
+        }
+        nonces.release(&self.sender, *stx.transaction.unsigned.nonce())
+    }
+
+    fn sign(&self, params: &TransactionParams, nonce: U256) -> Result<SignedTransaction> {
+        let unsigned = params.to_unsigned(nonce);
+        let utx = UnverifiedTransaction {
+            unsigned,
+            signature: None,
+            chain_id: Some(self.chain_id),
+            hash: H256::default(),
+        };
+        let msg_hash = utx.signature_hash(true);
+        let signature = Secp256k1Recoverable::sign_message(msg_hash.as_bytes(), &self.private_key)
+            .map_err(|err| {
+                let errmsg = format!("failed to sign the transaction since {err}");
+                Error::Axon(errmsg)
+            })?
+            .to_bytes();
+        let utx = UnverifiedTransaction {
+            signature: Some(SignatureComponents::from(signature)),
+            ..utx
+        }
+        .calc_hash();
+        SignedTransaction::from_unverified(utx).map_err(Into::into)
+    }
+}
+
+impl TransactionParams {
+    fn to_unsigned(&self, nonce: U256) -> UnsignedTransaction {
+        let action = self
+            .to
+            .map(TransactionAction::Call)
+            .unwrap_or(TransactionAction::Create);
+        let data = self.data.as_ref().map(Hex::as_bytes).unwrap_or_default();
+        match self.tx_type {
+            TransactionType::Legacy => UnsignedTransaction::Legacy(LegacyTransaction {
+                nonce,
+                gas_price: self.gas_price,
+                gas_limit: self.gas_limit,
+                action,
+                value: self.value,
+                data,
+            }),
+            TransactionType::Eip2930 => UnsignedTransaction::Eip2930(Eip2930Transaction {
+                nonce,
+                gas_price: self.gas_price,
+                gas_limit: self.gas_limit,
+                action,
+                value: self.value,
+                data,
+                access_list: Vec::new(),
+            }),
+            TransactionType::Eip1559 => UnsignedTransaction::Eip1559(Eip1559Transaction {
+                nonce,
+                max_priority_fee_per_gas: self.max_priority_fee_per_gas,
+                gas_price: self.gas_price,
+                gas_limit: self.gas_limit,
+                action,
+                value: self.value,
+                data,
+                access_list: Vec::new(),
+            }),
+        }
+    }
+}
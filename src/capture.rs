@@ -0,0 +1,142 @@
+use std::{
+    fs,
+    io::{BufRead as _, BufReader, BufWriter, Write as _},
+    path::Path,
+    sync::mpsc,
+    thread,
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    axon::{
+        core::network::{endpoint::Endpoint, NetworkContext as _},
+        protocol::{
+            traits::Context,
+            types::{Bytes, Hex},
+        },
+    },
+    result::{Error, Result},
+};
+
+/// A message which was received from the P2P network.
+///
+/// A capture file is a sequence of records, one JSON object per line.
+///
+/// The bytes are re-encoded from the decoded message, since the raw bytes are not exposed to
+/// message handlers. So they may differ from the received ones, and malformed messages, which
+/// are dropped before reaching any handler, are not recorded.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Record {
+    /// Unix timestamp in milliseconds.
+    pub timestamp: u64,
+    pub endpoint: String,
+    pub session_id: Option<usize>,
+    pub peer_id: Option<String>,
+    pub bytes: Hex,
+}
+
+/// How many records could be pending, the new records are dropped when the queue is full.
+const PENDING_RECORDS_LIMIT: usize = 16 * 1024;
+
+/// Appends records to a capture file.
+///
+/// Records are written by a dedicated thread, so appending never blocks on I/O.
+pub struct Recorder {
+    sender: mpsc::SyncSender<Record>,
+}
+
+impl Record {
+    pub fn new(ctx: &Context, endpoint: &Endpoint, bytes: Bytes) -> Self {
+        Self {
+            timestamp: faketime::unix_time_as_millis(),
+            endpoint: endpoint.to_string(),
+            session_id: ctx.session_id().ok().map(|id| id.value()),
+            peer_id: ctx.remote_peer_id().ok().map(|id| id.to_base58()),
+            bytes: Hex::encode(bytes),
+        }
+    }
+}
+
+impl Recorder {
+    /// Opens the capture file in append mode, creates it if it doesn't exist.
+    pub fn open(file_path: &Path) -> Result<Self> {
+        if let Some(dir) = file_path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        let file = fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(file_path)?;
+        let (sender, receiver) = mpsc::sync_channel(PENDING_RECORDS_LIMIT);
+        thread::Builder::new()
+            .name("recorder".to_owned())
+            .spawn(move || write_records(BufWriter::new(file), receiver))?;
+        Ok(Self { sender })
+    }
+
+    /// Queues the record, it's dropped if the writer falls behind.
+    pub fn append(&self, record: Record) -> Result<()> {
+        self.sender.try_send(record).map_err(|err| {
+            let errmsg = match err {
+                mpsc::TrySendError::Full(_) => {
+                    "too many pending records, the record is dropped".to_owned()
+                }
+                mpsc::TrySendError::Disconnected(_) => {
+                    "the writer of the capture file has stopped".to_owned()
+                }
+            };
+            Error::Capture(errmsg)
+        })
+    }
+}
+
+/// Writes records until all recorders are dropped, flushes whenever there are no pending records.
+fn write_records(mut writer: BufWriter<fs::File>, receiver: mpsc::Receiver<Record>) {
+    while let Ok(mut record) = receiver.recv() {
+        loop {
+            if let Err(err) = write_record(&mut writer, &record) {
+                log::warn!("failed to write a capture record since {err}");
+            }
+            match receiver.try_recv() {
+                Ok(next) => record = next,
+                Err(_) => break,
+            }
+        }
+        if let Err(err) = writer.flush() {
+            log::warn!("failed to flush the capture file since {err}");
+        }
+    }
+}
+
+fn write_record(writer: &mut BufWriter<fs::File>, record: &Record) -> Result<()> {
+    let mut line = serde_json::to_vec(record).map_err(|err| {
+        let errmsg = format!("failed to encode the capture record since {err}");
+        Error::Capture(errmsg)
+    })?;
+    line.push(b'\n');
+    writer.write_all(&line)?;
+    Ok(())
+}
+
+/// Reads all records from a capture file, in the order they were recorded.
+pub fn load_records(file_path: &Path) -> Result<Vec<Record>> {
+    let file = fs::File::open(file_path)?;
+    let mut records = Vec::new();
+    for (index, line) in BufReader::new(file).lines().enumerate() {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        let record = serde_json::from_str(&line).map_err(|err| {
+            let errmsg = format!(
+                "failed to parse line {} of capture file {} since {err}",
+                index + 1,
+                file_path.display()
+            );
+            Error::Capture(errmsg)
+        })?;
+        records.push(record);
+    }
+    Ok(records)
+}
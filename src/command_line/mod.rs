@@ -2,6 +2,10 @@ use clap::{Parser, Subcommand};
 
 use crate::result::Result;
 
+mod parsers;
+mod propose;
+mod replay;
+mod send;
 mod serve;
 
 #[derive(Parser, Debug)]
@@ -13,6 +17,9 @@ pub struct Cli {
 #[derive(Subcommand, Debug)]
 enum Commands {
     Serve(serve::Arguments),
+    Replay(replay::Arguments),
+    Send(send::Arguments),
+    Propose(propose::Arguments),
 }
 
 impl Cli {
@@ -23,6 +30,9 @@ impl Cli {
     pub fn execute(self) -> Result<()> {
         match self.command {
             Commands::Serve(args) => args.execute(),
+            Commands::Replay(args) => args.execute(),
+            Commands::Send(args) => args.execute(),
+            Commands::Propose(args) => args.execute(),
         }
     }
 }
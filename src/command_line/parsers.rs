@@ -0,0 +1,29 @@
+use std::{result::Result as StdResult, str::FromStr as _};
+
+use crate::{
+    axon::protocol::types::{Hex, H160, H256, U256},
+    builders::consensus::LockParams,
+};
+
+pub(crate) fn parse_h256(value: &str) -> StdResult<H256, String> {
+    H256::from_str(value).map_err(|err| format!("failed to parse {value:?} since {err}"))
+}
+
+pub(crate) fn parse_h160(value: &str) -> StdResult<H160, String> {
+    H160::from_str(value).map_err(|err| format!("failed to parse {value:?} since {err}"))
+}
+
+pub(crate) fn parse_u256(value: &str) -> StdResult<U256, String> {
+    U256::from_dec_str(value).map_err(|err| format!("failed to parse {value:?} since {err:?}"))
+}
+
+pub(crate) fn parse_hex(value: &str) -> StdResult<Hex, String> {
+    let value = value.strip_prefix("0x").unwrap_or(value);
+    hex::decode(value)
+        .map(Hex::encode)
+        .map_err(|err| format!("failed to parse {value:?} since {err}"))
+}
+
+pub(crate) fn parse_lock(value: &str) -> StdResult<LockParams, String> {
+    serde_json::from_str(value).map_err(|err| format!("failed to parse {value:?} since {err}"))
+}
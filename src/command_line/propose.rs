@@ -0,0 +1,107 @@
+use clap::Parser;
+
+use crate::{
+    axon::{
+        protocol::types::{Hasher, Hex},
+        services::{
+            endpoints::consensus::END_GOSSIP_SIGNED_PROPOSAL, messages::consensus::Proposal,
+        },
+    },
+    builders::consensus::{ConsensusBuilder, LockParams, ProposalParams},
+    configs::serve::Config,
+    result::Result,
+    service::SendService,
+};
+
+use super::parsers::{parse_hex, parse_lock};
+
+#[derive(Parser, Debug)]
+#[command(about = "Build an Overlord signed proposal, then broadcast it to an Axon network.")]
+pub struct Arguments {
+    #[arg(
+        short = 'c',
+        long = "config",
+        value_name = "CONFIG_FILE",
+        help = "File path of client configurations."
+    )]
+    config: Config,
+    #[arg(long = "height", value_name = "HEIGHT", help = "The height.")]
+    height: u64,
+    #[arg(
+        long = "round",
+        value_name = "ROUND",
+        default_value = "0",
+        help = "The round."
+    )]
+    round: u64,
+    #[arg(
+        long = "proposer",
+        value_name = "ADDRESS",
+        value_parser = parse_hex,
+        help = "The address of the proposer, in hexadecimal."
+    )]
+    proposer: Hex,
+    #[arg(
+        long = "content",
+        value_name = "CONTENT",
+        default_value = "0x",
+        value_parser = parse_hex,
+        help = "The encoded block proposal, in hexadecimal."
+    )]
+    content: Hex,
+    #[arg(
+        long = "block-hash",
+        value_name = "HASH",
+        value_parser = parse_hex,
+        help = "The block hash, use the hash of the content if it's omitted."
+    )]
+    block_hash: Option<Hex>,
+    #[arg(
+        long = "lock",
+        value_name = "LOCK",
+        value_parser = parse_lock,
+        help = "The lock, in JSON with fields \"lockRound\" and \"lockVotes\" (an aggregated vote)."
+    )]
+    lock: Option<LockParams>,
+    #[arg(
+        long = "signature",
+        value_name = "SIGNATURE",
+        value_parser = parse_hex,
+        help = "The signature, sign the proposal by the configured BLS private key if it's omitted."
+    )]
+    signature: Option<Hex>,
+}
+
+impl Arguments {
+    pub fn execute(self) -> Result<()> {
+        let Self {
+            config,
+            height,
+            round,
+            proposer,
+            content,
+            block_hash,
+            lock,
+            signature,
+        } = self;
+        let params = ProposalParams {
+            height,
+            round,
+            proposer,
+            content,
+            block_hash,
+            lock,
+            signature,
+        };
+        let builder = ConsensusBuilder::new(config.bls_key())?;
+        let signed_proposal = builder.signed_proposal(&params)?;
+        let bytes = rlp::encode(&signed_proposal).to_vec();
+        log::info!(
+            "Proposal {:#x} ({} bytes) is built",
+            Hasher::digest(&bytes),
+            bytes.len()
+        );
+        let service = SendService::new(&config, END_GOSSIP_SIGNED_PROPOSAL, Proposal(bytes))?;
+        service.run()
+    }
+}
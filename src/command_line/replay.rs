@@ -0,0 +1,63 @@
+use std::path::PathBuf;
+
+use clap::Parser;
+
+use crate::{
+    capture::load_records, configs::serve::Config, result::Result, service::ReplayService,
+};
+
+#[derive(Parser, Debug)]
+#[command(about = "Replay a capture file to an Axon network, with the original relative timing.")]
+pub struct Arguments {
+    #[arg(
+        short = 'c',
+        long = "config",
+        value_name = "CONFIG_FILE",
+        help = "File path of client configurations."
+    )]
+    config: Config,
+    #[arg(
+        short = 'f',
+        long = "capture-file",
+        value_name = "CAPTURE_FILE",
+        help = "File path of the capture file to replay."
+    )]
+    capture_file: PathBuf,
+    #[arg(
+        long = "speed",
+        value_name = "SPEED",
+        default_value = "1.0",
+        value_parser = parse_speed,
+        help = "Replay speed, for example, 2.0 is twice as fast and 0.5 is half as fast."
+    )]
+    speed: f64,
+}
+
+impl Arguments {
+    pub fn execute(self) -> Result<()> {
+        let Self {
+            config,
+            capture_file,
+            speed,
+        } = self;
+        let records = load_records(&capture_file)?;
+        log::info!(
+            "Loaded {} records from {}",
+            records.len(),
+            capture_file.display()
+        );
+        let service = ReplayService::new(&config, records, speed)?;
+        service.run()
+    }
+}
+
+fn parse_speed(value: &str) -> std::result::Result<f64, String> {
+    let speed = value
+        .parse::<f64>()
+        .map_err(|err| format!("failed to parse speed since {err}"))?;
+    if speed.is_finite() && speed > 0.0 {
+        Ok(speed)
+    } else {
+        Err(format!("speed should be a positive number but got {speed}"))
+    }
+}
@@ -0,0 +1,135 @@
+use clap::Parser;
+
+use crate::{
+    axon::{
+        protocol::types::{BatchSignedTxs, Hex, H160, H256, U256},
+        services::endpoints::mempool::END_GOSSIP_NEW_TXS,
+    },
+    builders::{
+        nonce::NonceManager,
+        transaction::{TransactionBuilder, TransactionParams, TransactionType},
+    },
+    configs::serve::Config,
+    result::Result,
+    service::SendService,
+};
+
+use super::parsers::{parse_h160, parse_h256, parse_hex, parse_u256};
+
+#[derive(Parser, Debug)]
+#[command(about = "Build and sign a transaction, then broadcast it to an Axon network.")]
+pub struct Arguments {
+    #[arg(
+        short = 'c',
+        long = "config",
+        value_name = "CONFIG_FILE",
+        help = "File path of client configurations."
+    )]
+    config: Config,
+    #[arg(
+        long = "private-key",
+        value_name = "PRIVATE_KEY",
+        value_parser = parse_h256,
+        help = "The private key to sign the transaction, in hexadecimal."
+    )]
+    private_key: H256,
+    #[arg(
+        long = "type",
+        value_name = "TYPE",
+        value_enum,
+        default_value = "eip1559",
+        help = "The type of the transaction."
+    )]
+    tx_type: TransactionType,
+    #[arg(
+        long = "nonce",
+        value_name = "NONCE",
+        value_parser = parse_u256,
+        help = "The nonce, use the next nonce in the local nonce tracker if it's omitted."
+    )]
+    nonce: Option<U256>,
+    #[arg(
+        long = "to",
+        value_name = "ADDRESS",
+        value_parser = parse_h160,
+        help = "The receiver, create a contract if it's omitted."
+    )]
+    to: Option<H160>,
+    #[arg(
+        long = "value",
+        value_name = "VALUE",
+        default_value = "0",
+        value_parser = parse_u256,
+        help = "The value to transfer, in wei."
+    )]
+    value: U256,
+    #[arg(
+        long = "data",
+        value_name = "DATA",
+        value_parser = parse_hex,
+        help = "The input data, in hexadecimal."
+    )]
+    data: Option<Hex>,
+    #[arg(
+        long = "gas-limit",
+        value_name = "GAS_LIMIT",
+        default_value = "21000",
+        value_parser = parse_u256,
+        help = "The gas limit."
+    )]
+    gas_limit: U256,
+    #[arg(
+        long = "gas-price",
+        value_name = "GAS_PRICE",
+        value_parser = parse_u256,
+        help = "The gas price, or the max fee per gas for EIP-1559 transactions."
+    )]
+    gas_price: U256,
+    #[arg(
+        long = "max-priority-fee-per-gas",
+        value_name = "FEE",
+        default_value = "0",
+        value_parser = parse_u256,
+        help = "The max priority fee per gas, only for EIP-1559 transactions."
+    )]
+    max_priority_fee_per_gas: U256,
+}
+
+impl Arguments {
+    pub fn execute(self) -> Result<()> {
+        let Self {
+            config,
+            private_key,
+            tx_type,
+            nonce,
+            to,
+            value,
+            data,
+            gas_limit,
+            gas_price,
+            max_priority_fee_per_gas,
+        } = self;
+        let params = TransactionParams {
+            tx_type,
+            nonce,
+            to,
+            value,
+            data,
+            gas_limit,
+            gas_price,
+            max_priority_fee_per_gas,
+        };
+        let nonces = NonceManager::load(&config.nonces_file())?;
+        let builder = TransactionBuilder::new(config.chain_id(), private_key)?;
+        let stx = builder.build(&params, &nonces)?;
+        log::info!("Transaction {:#x} is built", stx.transaction.hash);
+        let stxs = BatchSignedTxs::new(vec![stx.clone()]);
+        let ret = SendService::new(&config, END_GOSSIP_NEW_TXS, stxs).and_then(SendService::run);
+        if ret.is_err() {
+            let _ignore = builder
+                .release(&params, &stx, &nonces)
+                .map_err(|err| log::warn!("failed to release the nonce since {err}"));
+        }
+        ret
+    }
+}
@@ -0,0 +1,31 @@
+use serde::Deserialize;
+
+use crate::result::{Error, Result};
+
+use super::types::RpcPeer;
+
+/// How to announce fake heights.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct AnnouncePlan {
+    /// The first announced height.
+    pub height: u64,
+    /// Added to the height after each announcement, a negative step makes the height regress.
+    #[serde(default)]
+    pub step: i64,
+    /// Seconds between two announcements.
+    pub interval: u64,
+    /// Announce to all peers if it's omitted.
+    pub peers: Option<Vec<RpcPeer>>,
+}
+
+impl AnnouncePlan {
+    pub fn check(&self) -> Result<()> {
+        if self.interval == 0 {
+            let errmsg = "the interval of height announcements should be greater than zero";
+            Err(Error::Announcer(errmsg.to_owned()))
+        } else {
+            Ok(())
+        }
+    }
+}
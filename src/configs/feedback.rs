@@ -0,0 +1,34 @@
+use serde::Deserialize;
+
+use crate::axon::protocol::traits::TrustFeedback;
+
+/// The trust feedback which is returned after a message is processed.
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case", deny_unknown_fields)]
+pub enum Feedback {
+    #[default]
+    Neutral,
+    Good,
+    Bad {
+        message: String,
+    },
+    Worse {
+        message: String,
+    },
+    /// The remote peer will be disconnected and banned.
+    Fatal {
+        message: String,
+    },
+}
+
+impl From<Feedback> for TrustFeedback {
+    fn from(feedback: Feedback) -> Self {
+        match feedback {
+            Feedback::Neutral => Self::Neutral,
+            Feedback::Good => Self::Good,
+            Feedback::Bad { message } => Self::Bad(message),
+            Feedback::Worse { message } => Self::Worse(message),
+            Feedback::Fatal { message } => Self::Fatal(message),
+        }
+    }
+}
@@ -1 +1,6 @@
+pub mod announcer;
+pub mod feedback;
+pub mod protocols;
+pub mod responders;
 pub mod serve;
+pub mod types;
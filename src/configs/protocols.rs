@@ -0,0 +1,118 @@
+use serde::Deserialize;
+use tentacle::multiaddr::MultiAddr;
+
+use crate::result::{Error, Result};
+
+/// Which implementation is used for each protocol.
+///
+/// The transmitter protocol is always the standard one.
+/// Without the standard identify protocol, connections could not be established completely,
+/// since other protocols are opened after identified.
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Protocols {
+    #[serde(default)]
+    pub ping: PingMode,
+    #[serde(default)]
+    pub identify: IdentifyMode,
+    #[serde(default)]
+    pub discovery: DiscoveryMode,
+    #[serde(default)]
+    pub feeler: FeelerMode,
+}
+
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case", deny_unknown_fields)]
+pub enum PingMode {
+    #[default]
+    Standard,
+    /// Don't register the protocol.
+    Disabled,
+    /// Never send pings, never answer pings.
+    Silent,
+    /// Send pings, but never answer pings.
+    Stall,
+    /// Answer pings after `delay` milliseconds.
+    Delayed { delay: u64 },
+    /// Answer pings with mismatched nonces.
+    WrongNonce,
+    /// Send pings every `interval` milliseconds, and answer pings honestly.
+    Flood { interval: u64 },
+}
+
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case", deny_unknown_fields)]
+pub enum IdentifyMode {
+    #[default]
+    Standard,
+    /// Don't register the protocol.
+    Disabled,
+    /// Never identify.
+    Silent,
+    /// Advertise spoofed data.
+    Spoofed(SpoofedIdentity),
+}
+
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case", deny_unknown_fields)]
+pub enum DiscoveryMode {
+    #[default]
+    Standard,
+    /// Don't register the protocol.
+    Disabled,
+    /// Never request nodes, never answer requests.
+    Silent,
+    /// Answer requests with poisoned addresses.
+    Poisoned(PoisonedAddresses),
+}
+
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case", deny_unknown_fields)]
+pub enum FeelerMode {
+    #[default]
+    Standard,
+    /// Don't register the protocol.
+    Disabled,
+    /// Never close feeler connections.
+    Silent,
+}
+
+/// Overrides of the identify message, the omitted fields are truthful.
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct SpoofedIdentity {
+    pub chain_id: Option<u64>,
+    pub client_version: Option<String>,
+    pub protocols: Option<Vec<String>>,
+    pub listen_addrs: Option<Vec<MultiAddr>>,
+    /// The address of the remote peer which is observed by the local node.
+    pub observed_addr: Option<MultiAddr>,
+}
+
+/// Addresses which are advertised instead of the known ones.
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct PoisonedAddresses {
+    /// Fixed addresses, for example, unreachable or attacker-controlled ones.
+    #[serde(default)]
+    pub addresses: Vec<MultiAddr>,
+    /// How many random addresses, with random peer-ids, are appended.
+    #[serde(default)]
+    pub random: usize,
+}
+
+impl Protocols {
+    pub fn check(&self) -> Result<()> {
+        match self.ping {
+            PingMode::Delayed { delay: 0 } => {
+                let errmsg = "the delay of the delayed ping mode should be greater than zero";
+                Err(Error::Network(errmsg.to_owned()))
+            }
+            PingMode::Flood { interval: 0 } => {
+                let errmsg = "the interval of the flood ping mode should be greater than zero";
+                Err(Error::Network(errmsg.to_owned()))
+            }
+            _ => Ok(()),
+        }
+    }
+}
@@ -0,0 +1,101 @@
+use serde::Deserialize;
+
+use crate::result::{Error, Result};
+
+/// Responders for the RPC requests from peers, all requests are ignored by default.
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Responders {
+    pub pull_txs: Option<ResponderConfig>,
+    pub sync_pull_block: Option<ResponderConfig>,
+    pub sync_pull_proof: Option<ResponderConfig>,
+    pub sync_pull_txs: Option<ResponderConfig>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ResponderConfig {
+    #[serde(default)]
+    pub behavior: Behavior,
+    /// Milliseconds to wait before responding.
+    #[serde(default)]
+    pub delay: u64,
+}
+
+/// How to respond a request.
+///
+/// Not all behaviors make sense for all requests, the unsuitable ones are rejected by `check`.
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case", deny_unknown_fields)]
+pub enum Behavior {
+    /// Never respond, so the requester has to wait until timeout.
+    #[default]
+    Ignore,
+    /// Respond random bytes.
+    Garbage { size: usize },
+    /// Respond synthetic blocks or proofs at the requested height,
+    /// or the known transactions of the requested ones.
+    Normal,
+    /// Respond synthetic blocks or proofs at the requested height plus the offset.
+    WrongHeight { offset: i64 },
+    /// Respond synthetic blocks or proofs with random signatures.
+    InvalidProof,
+    /// Respond at most `keep` transactions of the requested ones.
+    Truncated { keep: usize },
+    /// Respond the known transactions which are not requested.
+    Others,
+    /// Respond each known transaction of the requested ones `times` times.
+    Duplicated { times: usize },
+    /// Respond an error with the message.
+    Error { message: String },
+}
+
+impl Responders {
+    pub fn check(&self) -> Result<()> {
+        check_behavior("pull_txs", &self.pull_txs, Behavior::is_for_txs)?;
+        check_behavior(
+            "sync_pull_block",
+            &self.sync_pull_block,
+            Behavior::is_for_blocks,
+        )?;
+        check_behavior(
+            "sync_pull_proof",
+            &self.sync_pull_proof,
+            Behavior::is_for_blocks,
+        )?;
+        check_behavior("sync_pull_txs", &self.sync_pull_txs, Behavior::is_for_txs)?;
+        Ok(())
+    }
+}
+
+impl Behavior {
+    /// Whether the behavior makes sense for the requests of blocks or proofs.
+    fn is_for_blocks(&self) -> bool {
+        !matches!(
+            self,
+            Self::Truncated { .. } | Self::Others | Self::Duplicated { .. }
+        )
+    }
+
+    /// Whether the behavior makes sense for the requests of transactions.
+    fn is_for_txs(&self) -> bool {
+        !matches!(self, Self::WrongHeight { .. } | Self::InvalidProof)
+    }
+}
+
+fn check_behavior(
+    name: &str,
+    config: &Option<ResponderConfig>,
+    is_suitable: fn(&Behavior) -> bool,
+) -> Result<()> {
+    match config {
+        Some(config) if !is_suitable(&config.behavior) => {
+            let errmsg = format!(
+                "the behavior {:?} is unsuitable for the responder {name}",
+                config.behavior
+            );
+            Err(Error::Responder(errmsg))
+        }
+        _ => Ok(()),
+    }
+}
@@ -1,4 +1,5 @@
 use std::{
+    collections::{HashMap, HashSet},
     ffi::OsStr,
     fs,
     io::Read as _,
@@ -19,6 +20,12 @@ use crate::{
     result::Result,
 };
 
+use super::{
+    announcer::AnnouncePlan, feedback::Feedback, protocols::Protocols, responders::Responders,
+};
+
+const DEFAULT_TXPOOL_CAPACITY: usize = 100_000;
+
 #[derive(Clone, Debug, Deserialize)]
 #[serde(deny_unknown_fields)]
 pub struct Config {
@@ -26,6 +33,19 @@ pub struct Config {
     data_dir: PathBuf,
     network: Network,
     jsonrpc: Jsonrpc,
+    capture: Option<Capture>,
+    consensus: Option<Consensus>,
+    height_announcer: Option<AnnouncePlan>,
+    #[serde(default)]
+    responders: Responders,
+    #[serde(default)]
+    feedbacks: HashMap<String, Feedback>,
+    #[serde(default)]
+    sybils: Vec<Sybil>,
+    #[serde(default)]
+    protocols: Protocols,
+    #[serde(default)]
+    txpool: Txpool,
 }
 
 #[derive(Clone, Debug, Deserialize)]
@@ -53,6 +73,45 @@ pub struct Jsonrpc {
     pub(crate) max_connections: u32,
 }
 
+#[derive(Clone, Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Capture {
+    file: PathBuf,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Txpool {
+    capacity: usize,
+}
+
+impl Default for Txpool {
+    fn default() -> Self {
+        Self {
+            capacity: DEFAULT_TXPOOL_CAPACITY,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Consensus {
+    bls_keyfile: PathBuf,
+    #[serde(skip)]
+    pub bls_key: Key256Bits,
+}
+
+/// An extra identity in the P2P network, other settings are same as the section `[network]`.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Sybil {
+    pub name: String,
+    keyfile: PathBuf,
+    #[serde(skip)]
+    pub key: Key256Bits,
+    listening_address: MultiAddr,
+}
+
 impl ValueParserFactory for Config {
     type Parser = ConfigValueParser;
 
@@ -100,6 +159,31 @@ impl TypedValueParser for ConfigValueParser {
             })
             .and_then(|mut config: Self::Value| {
                 config.network.key = load_key_from_file(&config.network.keyfile)?;
+                if let Some(consensus) = config.consensus.as_mut() {
+                    consensus.bls_key = load_key_from_file(&consensus.bls_keyfile)?;
+                }
+                config.responders.check().map_err(|err| {
+                    let kind = clap::error::ErrorKind::InvalidValue;
+                    clap::Error::raw(kind, err.to_string())
+                })?;
+                if config.txpool.capacity == 0 {
+                    let kind = clap::error::ErrorKind::InvalidValue;
+                    let msg = "the capacity of the txpool should be greater than zero";
+                    return Err(clap::Error::raw(kind, msg));
+                }
+                config.protocols.check().map_err(|err| {
+                    let kind = clap::error::ErrorKind::InvalidValue;
+                    clap::Error::raw(kind, err.to_string())
+                })?;
+                let mut names = HashSet::new();
+                for sybil in config.sybils.iter_mut() {
+                    if !names.insert(sybil.name.clone()) {
+                        let kind = clap::error::ErrorKind::InvalidValue;
+                        let msg = format!("the name of sybil {:?} is duplicated", sybil.name);
+                        return Err(clap::Error::raw(kind, msg));
+                    }
+                    sybil.key = load_key_from_file(&sybil.keyfile)?;
+                }
                 Ok(config)
             })
     }
@@ -139,17 +223,34 @@ fn load_key_from_file(keyfile_path: &Path) -> StdResult<Key256Bits, clap::Error>
 
 impl Config {
     pub fn network(&self) -> Result<NetworkConfig> {
+        self.build_network(
+            self.data_dir.clone(),
+            self.network.listening_address.clone(),
+        )
+    }
+
+    /// The peer store of each sybil is stored in its own directory, under `data_dir/sybils`.
+    pub fn sybil_network(&self, sybil: &Sybil) -> Result<NetworkConfig> {
+        let data_dir = self.data_dir.join("sybils").join(&sybil.name);
+        self.build_network(data_dir, sybil.listening_address.clone())
+    }
+
+    fn build_network(
+        &self,
+        data_dir: PathBuf,
+        listening_address: MultiAddr,
+    ) -> Result<NetworkConfig> {
         let config = self.network.clone();
         NetworkConfig::new()
             .chain_id(self.chain_id)
-            .peer_store_dir(self.data_dir.clone())
+            .peer_store_dir(data_dir)
             .bootstraps(
                 config
                     .bootstraps
                     .map(|addrs| addrs.into_iter().map(|addr| addr.multi_address).collect())
                     .unwrap_or_default(),
             )
-            .listen_addr(config.listening_address)
+            .listen_addr(listening_address)
             .send_buffer_size(config.send_buffer_size)
             .recv_buffer_size(config.recv_buffer_size)
             .max_frame_length(config.max_frame_length)
@@ -158,6 +259,10 @@ impl Config {
             .map_err(Into::into)
     }
 
+    pub fn chain_id(&self) -> u64 {
+        self.chain_id
+    }
+
     pub fn jsonrpc(&self) -> Jsonrpc {
         self.jsonrpc.clone()
     }
@@ -165,4 +270,42 @@ impl Config {
     pub fn network_key(&self) -> &Key256Bits {
         &self.network.key
     }
+
+    pub fn bls_key(&self) -> Option<&Key256Bits> {
+        self.consensus.as_ref().map(|consensus| &consensus.bls_key)
+    }
+
+    pub fn height_announcer(&self) -> Option<AnnouncePlan> {
+        self.height_announcer.clone()
+    }
+
+    pub fn responders(&self) -> Responders {
+        self.responders.clone()
+    }
+
+    pub fn feedbacks(&self) -> HashMap<String, Feedback> {
+        self.feedbacks.clone()
+    }
+
+    pub fn protocols(&self) -> Protocols {
+        self.protocols.clone()
+    }
+
+    pub fn txpool_capacity(&self) -> usize {
+        self.txpool.capacity
+    }
+
+    pub fn sybils(&self) -> &[Sybil] {
+        &self.sybils
+    }
+
+    pub fn nonces_file(&self) -> PathBuf {
+        self.data_dir.join("nonces.json")
+    }
+
+    pub fn capture_file(&self) -> Option<PathBuf> {
+        self.capture
+            .as_ref()
+            .map(|capture| self.data_dir.join(&capture.file))
+    }
 }
@@ -0,0 +1,40 @@
+use std::str::FromStr as _;
+
+use serde::{de, Deserialize, Deserializer};
+use tentacle::{multiaddr::Multiaddr, secio::PeerId, utils::extract_peer_id};
+
+use crate::axon::protocol::types::Bytes;
+
+/// A peer, specified by its peer-id, or by a multiaddr which contains its peer-id.
+#[derive(Clone, Debug)]
+pub struct RpcPeer(PeerId);
+
+impl RpcPeer {
+    pub fn peer_id(&self) -> &PeerId {
+        &self.0
+    }
+
+    pub fn to_bytes(&self) -> Bytes {
+        Bytes::from(self.0.as_bytes().to_vec())
+    }
+}
+
+impl<'de> Deserialize<'de> for RpcPeer {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        if let Ok(peer_id) = PeerId::from_str(&s) {
+            return Ok(Self(peer_id));
+        }
+        let addr = Multiaddr::from_str(&s).map_err(|err| {
+            de::Error::custom(format!(
+                "{s:?} is neither a peer-id nor a multiaddr ({err})"
+            ))
+        })?;
+        extract_peer_id(&addr)
+            .map(Self)
+            .ok_or_else(|| de::Error::custom(format!("failed to extract peer-id from {s:?}")))
+    }
+}
@@ -1,4 +1,6 @@
 pub mod axon;
+pub mod builders;
+pub mod capture;
 pub mod command_line;
 pub mod configs;
 pub mod result;
@@ -5,7 +5,9 @@ use tentacle::{
 };
 use thiserror::Error;
 
-use crate::axon::protocol::ProtocolError as AxonProtocolError;
+use crate::axon::protocol::{
+    ProtocolError as AxonProtocolError, ProtocolErrorKind as AxonProtocolErrorKind,
+};
 
 #[derive(Debug, Error)]
 pub enum Error {
@@ -27,6 +29,16 @@ pub enum Error {
     Io(#[from] std::io::Error),
     #[error("axon error: {0}")]
     Axon(String),
+    #[error("capture error: {0}")]
+    Capture(String),
+    #[error("nonce error: {0}")]
+    Nonce(String),
+    #[error("consensus error: {0}")]
+    Consensus(String),
+    #[error("announcer error: {0}")]
+    Announcer(String),
+    #[error("responder error: {0}")]
+    Responder(String),
 }
 
 pub type Result<T> = ::std::result::Result<T, Error>;
@@ -37,6 +49,12 @@ impl From<AxonProtocolError> for Error {
     }
 }
 
+impl From<Error> for AxonProtocolError {
+    fn from(error: Error) -> Self {
+        Self::new(AxonProtocolErrorKind::Network, Box::new(error))
+    }
+}
+
 #[derive(Clone)]
 pub struct RpcError {
     code: i32,
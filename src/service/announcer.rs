@@ -0,0 +1,89 @@
+use std::time::Duration;
+
+use tokio::{sync::watch, time::interval};
+
+use crate::{
+    axon::{
+        core::network::NetworkGossip,
+        protocol::traits::{Context, Gossip, Priority},
+        services::endpoints::synchronization::BROADCAST_HEIGHT,
+    },
+    configs::{announcer::AnnouncePlan, types::RpcPeer},
+    result::Result,
+};
+
+/// Announces fake heights through `BROADCAST_HEIGHT`, the plan could be changed at runtime.
+pub struct HeightAnnouncer {
+    plan: watch::Sender<Option<AnnouncePlan>>,
+}
+
+impl HeightAnnouncer {
+    pub fn new(plan: Option<AnnouncePlan>) -> Result<Self> {
+        if let Some(plan) = plan.as_ref() {
+            plan.check()?;
+        }
+        let (plan, _) = watch::channel(plan);
+        Ok(Self { plan })
+    }
+
+    pub fn start(&self, plan: AnnouncePlan) -> Result<()> {
+        plan.check()?;
+        self.plan.send_replace(Some(plan));
+        Ok(())
+    }
+
+    pub fn stop(&self) {
+        self.plan.send_replace(None);
+    }
+
+    pub async fn run(&self, gossip: NetworkGossip) {
+        let mut receiver = self.plan.subscribe();
+        loop {
+            let plan = receiver.borrow_and_update().clone();
+            let Some(plan) = plan else {
+                log::info!("height announcer is idle");
+                if receiver.changed().await.is_err() {
+                    break;
+                }
+                continue;
+            };
+            log::info!("height announcer starts with {plan:?}");
+            let mut height = plan.height;
+            let mut interval = interval(Duration::from_secs(plan.interval));
+            loop {
+                tokio::select! {
+                    _ = interval.tick() => {
+                        announce_height(&gossip, height, plan.peers.as_deref()).await;
+                        height = if plan.step < 0 {
+                            height.saturating_sub(plan.step.unsigned_abs())
+                        } else {
+                            height.saturating_add(plan.step.unsigned_abs())
+                        };
+                    }
+                    ret = receiver.changed() => {
+                        if ret.is_err() {
+                            return;
+                        }
+                        break;
+                    }
+                }
+            }
+        }
+    }
+}
+
+async fn announce_height(gossip: &NetworkGossip, height: u64, peers: Option<&[RpcPeer]>) {
+    let ctx = Context::new();
+    let ep = BROADCAST_HEIGHT;
+    let pri = Priority::High;
+    log::debug!("announce height {height} ...");
+    let ret = if let Some(peers) = peers {
+        let peer_ids = peers.iter().map(RpcPeer::to_bytes).collect::<Vec<_>>();
+        gossip.multicast(ctx, ep, peer_ids, height, pri).await
+    } else {
+        gossip.broadcast(ctx, ep, height, pri).await
+    };
+    if let Err(err) = ret {
+        log::warn!("failed to announce height {height} since {err}");
+    }
+}
@@ -0,0 +1,169 @@
+use async_trait::async_trait;
+use jsonrpsee::{core::RpcResult, proc_macros::rpc};
+
+use crate::{
+    axon::{
+        core::network::NetworkGossip,
+        protocol::{
+            traits::{Context, Gossip, MessageCodec, Priority},
+            types::Hex,
+        },
+        services::{
+            endpoints::consensus::{
+                END_GOSSIP_AGGREGATED_VOTE, END_GOSSIP_SIGNED_CHOKE, END_GOSSIP_SIGNED_PROPOSAL,
+                END_GOSSIP_SIGNED_VOTE,
+            },
+            messages::consensus::{Choke, Proposal, Vote, QC},
+        },
+    },
+    builders::consensus::{
+        AggregatedVoteParams, ChokeParams, ConsensusBuilder, ProposalParams, VoteParams,
+    },
+    configs::types::RpcPeer,
+    result::RpcError,
+};
+
+#[rpc(server)]
+pub trait ConsensusRpc {
+    /// Sends an Overlord signed vote, returns the encoded message.
+    ///
+    /// The vote is broadcasted if no peers are specified.
+    #[method(name = "injector_sendSignedVote")]
+    async fn send_signed_vote(
+        &self,
+        vote: VoteParams,
+        peers: Option<Vec<RpcPeer>>,
+    ) -> RpcResult<Hex>;
+
+    /// Sends an Overlord aggregated vote, returns the encoded message.
+    ///
+    /// The vote is broadcasted if no peers are specified.
+    #[method(name = "injector_sendAggregatedVote")]
+    async fn send_aggregated_vote(
+        &self,
+        vote: AggregatedVoteParams,
+        peers: Option<Vec<RpcPeer>>,
+    ) -> RpcResult<Hex>;
+
+    /// Sends an Overlord signed proposal, returns the encoded message.
+    ///
+    /// The proposal is broadcasted if no peers are specified.
+    #[method(name = "injector_sendSignedProposal")]
+    async fn send_signed_proposal(
+        &self,
+        proposal: ProposalParams,
+        peers: Option<Vec<RpcPeer>>,
+    ) -> RpcResult<Hex>;
+
+    /// Sends an Overlord signed choke, returns the encoded message.
+    ///
+    /// The choke is broadcasted if no peers are specified.
+    #[method(name = "injector_sendSignedChoke")]
+    async fn send_signed_choke(
+        &self,
+        choke: ChokeParams,
+        peers: Option<Vec<RpcPeer>>,
+    ) -> RpcResult<Hex>;
+}
+
+pub struct ConsensusRpcImpl {
+    gossip: NetworkGossip,
+    builder: ConsensusBuilder,
+}
+
+impl ConsensusRpcImpl {
+    pub fn new(gossip: NetworkGossip, builder: ConsensusBuilder) -> Self {
+        Self { gossip, builder }
+    }
+}
+
+#[async_trait]
+impl ConsensusRpcServer for ConsensusRpcImpl {
+    async fn send_signed_vote(
+        &self,
+        vote: VoteParams,
+        peers: Option<Vec<RpcPeer>>,
+    ) -> RpcResult<Hex> {
+        let signed_vote = self
+            .builder
+            .signed_vote(&vote)
+            .map_err(|e| RpcError::new(-1, e.to_string()))?;
+        let bytes = rlp::encode(&signed_vote).to_vec();
+        let encoded = Hex::encode(&bytes);
+        self.transmit(END_GOSSIP_SIGNED_VOTE, Vote(bytes), peers)
+            .await?;
+        Ok(encoded)
+    }
+
+    async fn send_aggregated_vote(
+        &self,
+        vote: AggregatedVoteParams,
+        peers: Option<Vec<RpcPeer>>,
+    ) -> RpcResult<Hex> {
+        let aggregated_vote = self
+            .builder
+            .aggregated_vote(&vote)
+            .map_err(|e| RpcError::new(-1, e.to_string()))?;
+        let bytes = rlp::encode(&aggregated_vote).to_vec();
+        let encoded = Hex::encode(&bytes);
+        self.transmit(END_GOSSIP_AGGREGATED_VOTE, QC(bytes), peers)
+            .await?;
+        Ok(encoded)
+    }
+
+    async fn send_signed_proposal(
+        &self,
+        proposal: ProposalParams,
+        peers: Option<Vec<RpcPeer>>,
+    ) -> RpcResult<Hex> {
+        let signed_proposal = self
+            .builder
+            .signed_proposal(&proposal)
+            .map_err(|e| RpcError::new(-1, e.to_string()))?;
+        let bytes = rlp::encode(&signed_proposal).to_vec();
+        let encoded = Hex::encode(&bytes);
+        self.transmit(END_GOSSIP_SIGNED_PROPOSAL, Proposal(bytes), peers)
+            .await?;
+        Ok(encoded)
+    }
+
+    async fn send_signed_choke(
+        &self,
+        choke: ChokeParams,
+        peers: Option<Vec<RpcPeer>>,
+    ) -> RpcResult<Hex> {
+        let signed_choke = self
+            .builder
+            .signed_choke(&choke)
+            .map_err(|e| RpcError::new(-1, e.to_string()))?;
+        let bytes = rlp::encode(&signed_choke).to_vec();
+        let encoded = Hex::encode(&bytes);
+        self.transmit(END_GOSSIP_SIGNED_CHOKE, Choke(bytes), peers)
+            .await?;
+        Ok(encoded)
+    }
+}
+
+impl ConsensusRpcImpl {
+    async fn transmit<M: MessageCodec>(
+        &self,
+        endpoint: &str,
+        msg: M,
+        peers: Option<Vec<RpcPeer>>,
+    ) -> RpcResult<()> {
+        let ctx = Context::new();
+        let pri = Priority::High;
+        if let Some(peers) = peers {
+            let peer_ids = peers.iter().map(RpcPeer::to_bytes).collect::<Vec<_>>();
+            log::debug!("gossip multicast to {} peers ...", peer_ids.len());
+            self.gossip
+                .multicast(ctx, endpoint, peer_ids, msg, pri)
+                .await
+        } else {
+            log::debug!("gossip broadcast ...");
+            self.gossip.broadcast(ctx, endpoint, msg, pri).await
+        }
+        .map_err(|e| RpcError::new(-1, e.to_string()))?;
+        Ok(())
+    }
+}
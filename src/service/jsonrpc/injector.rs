@@ -0,0 +1,302 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use jsonrpsee::{core::RpcResult, proc_macros::rpc};
+
+use crate::{
+    axon::{
+        core::network::{endpoint::Endpoint, NetworkGossip},
+        protocol::{
+            codec::ProtocolCodec,
+            traits::{Context, Gossip, MessageCodec, Priority},
+            types::{
+                BatchSignedTxs, Bytes, Hex, SignedTransaction, UnverifiedTransaction, H160, H256,
+                U256,
+            },
+        },
+        services::{endpoints::mempool::END_GOSSIP_NEW_TXS, messages::RawMessage},
+    },
+    builders::{
+        nonce::NonceManager,
+        transaction::{TransactionBuilder, TransactionParams},
+    },
+    configs::types::RpcPeer,
+    result::RpcError,
+    service::txpool::TxPool,
+};
+
+use super::{types::RpcPriority, web3::decode_signed_transaction};
+
+#[rpc(server)]
+pub trait InjectorRpc {
+    /// Broadcasts the bytes to an endpoint as-is, without any checks.
+    #[method(name = "injector_gossipRaw")]
+    async fn gossip_raw(
+        &self,
+        endpoint: String,
+        bytes: Hex,
+        priority: Option<RpcPriority>,
+    ) -> RpcResult<()>;
+
+    /// Sends the bytes to an endpoint of the specified peers as-is, without any checks.
+    #[method(name = "injector_multicastRaw")]
+    async fn multicast_raw(
+        &self,
+        endpoint: String,
+        peers: Vec<RpcPeer>,
+        bytes: Hex,
+        priority: Option<RpcPriority>,
+    ) -> RpcResult<()>;
+
+    /// Sends a signed transaction to the specified peers only.
+    #[method(name = "injector_sendRawTransactionTo")]
+    async fn send_transaction_to(&self, peers: Vec<RpcPeer>, tx: Hex) -> RpcResult<H256>;
+
+    /// Broadcasts signed transactions in batches.
+    ///
+    /// All transactions are packed into one batch if the batch size is omitted.
+    #[method(name = "injector_sendRawTransactions")]
+    async fn send_transactions(
+        &self,
+        txs: Vec<Hex>,
+        batch_size: Option<usize>,
+    ) -> RpcResult<Vec<H256>>;
+
+    /// Broadcasts a transaction without recovering its sender from the signature.
+    ///
+    /// The sender is specified by the caller, and the signature could be corrupted on purpose.
+    #[method(name = "injector_sendUncheckedTransaction")]
+    async fn send_unchecked_transaction(
+        &self,
+        tx: Hex,
+        sender: H160,
+        corrupt_signature: Option<bool>,
+    ) -> RpcResult<H256>;
+
+    /// Builds and signs a transaction with the private key, then broadcasts it.
+    #[method(name = "injector_buildAndSend")]
+    async fn build_and_send(&self, private_key: H256, tx: TransactionParams) -> RpcResult<H256>;
+
+    /// Returns the nonce which will be used by the next built transaction of the account.
+    #[method(name = "injector_getNonce")]
+    async fn get_nonce(&self, address: H160) -> RpcResult<U256>;
+
+    /// Overrides the nonce which will be used by the next built transaction of the account.
+    #[method(name = "injector_setNonce")]
+    async fn set_nonce(&self, address: H160, nonce: U256) -> RpcResult<()>;
+
+    /// Resets the nonce of the account to zero.
+    #[method(name = "injector_resetNonce")]
+    async fn reset_nonce(&self, address: H160) -> RpcResult<()>;
+}
+
+pub struct InjectorRpcImpl {
+    chain_id: u64,
+    gossip: NetworkGossip,
+    nonces: Arc<NonceManager>,
+    txpool: Arc<TxPool>,
+}
+
+impl InjectorRpcImpl {
+    pub fn new(
+        chain_id: u64,
+        gossip: NetworkGossip,
+        nonces: Arc<NonceManager>,
+        txpool: Arc<TxPool>,
+    ) -> Self {
+        Self {
+            chain_id,
+            gossip,
+            nonces,
+            txpool,
+        }
+    }
+}
+
+#[async_trait]
+impl InjectorRpcServer for InjectorRpcImpl {
+    async fn gossip_raw(
+        &self,
+        endpoint: String,
+        bytes: Hex,
+        priority: Option<RpcPriority>,
+    ) -> RpcResult<()> {
+        let ctx = Context::new();
+        // Only check the format, the endpoint is not required to be registered.
+        endpoint
+            .parse::<Endpoint>()
+            .map_err(|e| RpcError::new(-1, e.to_string()))?;
+        let msg = RawMessage(bytes.as_bytes());
+        let pri = priority.unwrap_or_default().into();
+
+        log::debug!("gossip broadcast raw bytes to {endpoint} ...");
+        self.gossip
+            .broadcast(ctx, &endpoint, msg, pri)
+            .await
+            .map_err(|e| RpcError::new(-1, e.to_string()))?;
+
+        Ok(())
+    }
+
+    async fn multicast_raw(
+        &self,
+        endpoint: String,
+        peers: Vec<RpcPeer>,
+        bytes: Hex,
+        priority: Option<RpcPriority>,
+    ) -> RpcResult<()> {
+        endpoint
+            .parse::<Endpoint>()
+            .map_err(|e| RpcError::new(-1, e.to_string()))?;
+        let msg = RawMessage(bytes.as_bytes());
+        let pri = priority.unwrap_or_default().into();
+        self.multicast(&endpoint, &peers, msg, pri).await
+    }
+
+    async fn send_transaction_to(&self, peers: Vec<RpcPeer>, tx: Hex) -> RpcResult<H256> {
+        let stx = decode_signed_transaction(&tx)?;
+        let tx_hash = stx.transaction.hash;
+        self.txpool.insert(stx.clone());
+        let stxs = BatchSignedTxs::new(vec![stx]);
+        self.multicast(END_GOSSIP_NEW_TXS, &peers, stxs, Priority::High)
+            .await?;
+        Ok(tx_hash)
+    }
+
+    async fn send_transactions(
+        &self,
+        txs: Vec<Hex>,
+        batch_size: Option<usize>,
+    ) -> RpcResult<Vec<H256>> {
+        let batch_size = match batch_size {
+            Some(0) => {
+                let errmsg = "batch size should be greater than zero".to_owned();
+                return Err(RpcError::new(-1, errmsg).into());
+            }
+            Some(size) => size,
+            None => txs.len().max(1),
+        };
+        let stxs = txs
+            .iter()
+            .map(decode_signed_transaction)
+            .collect::<RpcResult<Vec<_>>>()?;
+        let tx_hashes = stxs.iter().map(|stx| stx.transaction.hash).collect();
+        for stx in &stxs {
+            self.txpool.insert(stx.clone());
+        }
+
+        for (index, chunk) in stxs.chunks(batch_size).enumerate() {
+            let ctx = Context::new();
+            let batch = BatchSignedTxs::new(chunk.to_vec());
+
+            log::debug!("gossip broadcast batch {index} ({} txs) ...", chunk.len());
+            self.gossip
+                .broadcast(ctx, END_GOSSIP_NEW_TXS, batch, Priority::High)
+                .await
+                .map_err(|e| RpcError::new(-1, e.to_string()))?;
+        }
+
+        Ok(tx_hashes)
+    }
+
+    async fn send_unchecked_transaction(
+        &self,
+        tx: Hex,
+        sender: H160,
+        corrupt_signature: Option<bool>,
+    ) -> RpcResult<H256> {
+        let ctx = Context::new();
+        let ep = END_GOSSIP_NEW_TXS;
+        let tx_bytes = tx.as_bytes();
+        let mut utx = UnverifiedTransaction::decode(&tx_bytes)
+            .map_err(|e| RpcError::new(-1, e.to_string()))?;
+        if corrupt_signature.unwrap_or(false) {
+            let signature = utx.signature.as_mut().ok_or_else(|| {
+                RpcError::new(-1, "the transaction has no signature to corrupt".to_owned())
+            })?;
+            let corrupted = signature.r.iter().map(|b| !b).collect::<Vec<_>>();
+            signature.r = Bytes::from(corrupted);
+        }
+        let utx = utx.calc_hash();
+        let tx_hash = utx.hash;
+        let stx = SignedTransaction {
+            transaction: utx,
+            sender,
+            public: None,
+        };
+        self.txpool.insert(stx.clone());
+        let stxs = BatchSignedTxs::new(vec![stx]);
+        let pri = Priority::High;
+
+        log::debug!("gossip broadcast an unchecked transaction ...");
+        self.gossip
+            .broadcast(ctx, ep, stxs, pri)
+            .await
+            .map_err(|e| RpcError::new(-1, e.to_string()))?;
+
+        Ok(tx_hash)
+    }
+
+    async fn build_and_send(&self, private_key: H256, tx: TransactionParams) -> RpcResult<H256> {
+        let ctx = Context::new();
+        let ep = END_GOSSIP_NEW_TXS;
+        let builder = TransactionBuilder::new(self.chain_id, private_key)
+            .map_err(|e| RpcError::new(-1, e.to_string()))?;
+        let stx = builder
+            .build(&tx, &self.nonces)
+            .map_err(|e| RpcError::new(-1, e.to_string()))?;
+        let tx_hash = stx.transaction.hash;
+        let stxs = BatchSignedTxs::new(vec![stx.clone()]);
+        let pri = Priority::High;
+
+        log::debug!("gossip broadcast ...");
+        if let Err(e) = self.gossip.broadcast(ctx, ep, stxs, pri).await {
+            let _ignore = builder
+                .release(&tx, &stx, &self.nonces)
+                .map_err(|err| log::warn!("failed to release the nonce since {err}"));
+            return Err(RpcError::new(-1, e.to_string()).into());
+        }
+        self.txpool.insert(stx);
+
+        Ok(tx_hash)
+    }
+
+    async fn get_nonce(&self, address: H160) -> RpcResult<U256> {
+        self.nonces
+            .get(&address)
+            .map_err(|e| RpcError::new(-1, e.to_string()).into())
+    }
+
+    async fn set_nonce(&self, address: H160, nonce: U256) -> RpcResult<()> {
+        self.nonces
+            .set(&address, nonce)
+            .map_err(|e| RpcError::new(-1, e.to_string()).into())
+    }
+
+    async fn reset_nonce(&self, address: H160) -> RpcResult<()> {
+        self.nonces
+            .reset(&address)
+            .map_err(|e| RpcError::new(-1, e.to_string()).into())
+    }
+}
+
+impl InjectorRpcImpl {
+    async fn multicast<M: MessageCodec>(
+        &self,
+        endpoint: &str,
+        peers: &[RpcPeer],
+        msg: M,
+        pri: Priority,
+    ) -> RpcResult<()> {
+        let ctx = Context::new();
+        let peer_ids = peers.iter().map(RpcPeer::to_bytes).collect::<Vec<_>>();
+
+        log::debug!("gossip multicast to {} peers ...", peer_ids.len());
+        self.gossip
+            .multicast(ctx, endpoint, peer_ids, msg, pri)
+            .await
+            .map_err(|e| RpcError::new(-1, e.to_string()))?;
+
+        Ok(())
+    }
+}
@@ -2,35 +2,132 @@ use hyper::{header::CONTENT_TYPE, Method};
 use jsonrpsee::server::{ServerBuilder, ServerHandle};
 use tower_http::cors::{Any as CorsAny, CorsLayer};
 
+use std::sync::Arc;
+
+use tentacle::secio::PeerId;
+use tokio::sync::mpsc;
+
 use crate::{
-    axon::core::network::NetworkGossip,
+    axon::core::network::{peer_manager::PeerManager, NetworkConfig, NetworkGossip},
+    builders::{consensus::ConsensusBuilder, nonce::NonceManager},
     configs::serve::{Config, Jsonrpc as JsonrpcConfig},
     result::{Error, Result},
+    service::{
+        announcer::HeightAnnouncer,
+        network::{commands::NetworkCommand, NetworkService},
+        txpool::TxPool,
+    },
 };
 
+mod consensus;
+mod injector;
+mod network;
+mod sybil;
+mod synchronization;
+mod txpool;
+pub(crate) mod types;
 mod web3;
 
+use consensus::{ConsensusRpcImpl, ConsensusRpcServer as _};
+use injector::{InjectorRpcImpl, InjectorRpcServer as _};
+use network::{NetworkRpcImpl, NetworkRpcServer as _};
+use sybil::{SybilIdentity, SybilRpcImpl, SybilRpcServer as _};
+use synchronization::{SynchronizationRpcImpl, SynchronizationRpcServer as _};
+use txpool::{TxPoolRpcImpl, TxPoolRpcServer as _};
 use web3::{Web3RpcImpl, Web3RpcServer as _};
 
 pub struct JsonrpcService {
     config: JsonrpcConfig,
+    chain_id: u64,
     gossip: NetworkGossip,
+    nonces: Arc<NonceManager>,
+    consensus: ConsensusBuilder,
+    announcer: Arc<HeightAnnouncer>,
+    txpool: Arc<TxPool>,
+    network_config: Arc<NetworkConfig>,
+    peer_id: PeerId,
+    peer_manager: Arc<PeerManager>,
+    commander: mpsc::UnboundedSender<NetworkCommand>,
+    sybils: Vec<SybilIdentity>,
 }
 
 //
 // Public APIs
 //
 impl JsonrpcService {
-    pub fn new(raw_config: &Config, gossip: NetworkGossip) -> Result<Self> {
+    pub fn new(
+        raw_config: &Config,
+        network: &NetworkService,
+        sybils: &[NetworkService],
+        nonces: Arc<NonceManager>,
+        announcer: Arc<HeightAnnouncer>,
+    ) -> Result<Self> {
         let config = raw_config.jsonrpc();
-        Ok(Self { config, gossip })
+        let chain_id = raw_config.chain_id();
+        let consensus = ConsensusBuilder::new(raw_config.bls_key())?;
+        // Sybils are created in the same order as they are in the config.
+        let sybils = raw_config
+            .sybils()
+            .iter()
+            .zip(sybils)
+            .map(|(sybil, network)| SybilIdentity {
+                name: sybil.name.clone(),
+                peer_id: network.peer_id().clone(),
+                listening_address: network.config().default_listen.to_string(),
+                gossip: network.gossip().clone(),
+                peer_manager: Arc::clone(network.peer_manager()),
+            })
+            .collect();
+        Ok(Self {
+            config,
+            chain_id,
+            gossip: network.gossip().clone(),
+            nonces,
+            consensus,
+            announcer,
+            txpool: Arc::clone(network.txpool()),
+            network_config: Arc::clone(network.config()),
+            peer_id: network.peer_id().clone(),
+            peer_manager: Arc::clone(network.peer_manager()),
+            commander: network.commander().clone(),
+            sybils,
+        })
     }
 
     pub async fn start(self) -> Result<ServerHandle> {
         let config = &self.config;
 
         let addr = &config.listening_address;
-        let rpc = Web3RpcImpl::new(self.gossip().to_owned()).into_rpc();
+        let mut rpc =
+            Web3RpcImpl::new(self.gossip().to_owned(), Arc::clone(&self.txpool)).into_rpc();
+        rpc.merge(
+            InjectorRpcImpl::new(
+                self.chain_id,
+                self.gossip().to_owned(),
+                Arc::clone(&self.nonces),
+                Arc::clone(&self.txpool),
+            )
+            .into_rpc(),
+        )
+        .map_err(|e| Error::Jsonrpc(e.to_string()))?;
+        rpc.merge(TxPoolRpcImpl::new(Arc::clone(&self.txpool)).into_rpc())
+            .map_err(|e| Error::Jsonrpc(e.to_string()))?;
+        rpc.merge(
+            NetworkRpcImpl::new(
+                Arc::clone(&self.network_config),
+                self.peer_id.clone(),
+                Arc::clone(&self.peer_manager),
+                self.commander.clone(),
+            )
+            .into_rpc(),
+        )
+        .map_err(|e| Error::Jsonrpc(e.to_string()))?;
+        rpc.merge(SynchronizationRpcImpl::new(Arc::clone(&self.announcer)).into_rpc())
+            .map_err(|e| Error::Jsonrpc(e.to_string()))?;
+        rpc.merge(ConsensusRpcImpl::new(self.gossip().to_owned(), self.consensus).into_rpc())
+            .map_err(|e| Error::Jsonrpc(e.to_string()))?;
+        rpc.merge(SybilRpcImpl::new(self.sybils).into_rpc())
+            .map_err(|e| Error::Jsonrpc(e.to_string()))?;
 
         let cors = CorsLayer::new()
             .allow_methods([Method::GET, Method::POST, Method::OPTIONS])
@@ -0,0 +1,187 @@
+use std::{sync::Arc, time::Duration};
+
+use async_trait::async_trait;
+use jsonrpsee::{core::RpcResult, proc_macros::rpc};
+use tentacle::{bytes::Bytes, multiaddr::Multiaddr, secio::PeerId, utils::extract_peer_id};
+use tokio::sync::{mpsc, oneshot};
+
+use crate::{
+    axon::{
+        core::network::{peer_manager::PeerManager, NetworkConfig},
+        protocol::types::Hex,
+    },
+    configs::types::RpcPeer,
+    result::RpcError,
+    service::network::{
+        commands::NetworkCommand,
+        protocols::transmitter::{max_raw_frame_length, TransmitterFrame},
+    },
+};
+
+use super::types::{RpcNetworkStatus, RpcPeerInfo};
+
+#[rpc(server)]
+pub trait NetworkRpc {
+    /// Returns all connected peers.
+    #[method(name = "injector_peers")]
+    async fn peers(&self) -> RpcResult<Vec<RpcPeerInfo>>;
+
+    /// Returns the status of the local node and the counts of its connections.
+    #[method(name = "injector_networkStatus")]
+    async fn network_status(&self) -> RpcResult<RpcNetworkStatus>;
+
+    /// Dials a peer, the multiaddr should contain its peer-id.
+    #[method(name = "injector_dial")]
+    async fn dial(&self, addr: Multiaddr) -> RpcResult<()>;
+
+    /// Disconnects a connected peer.
+    #[method(name = "injector_disconnect")]
+    async fn disconnect(&self, peer: RpcPeer) -> RpcResult<()>;
+
+    /// Bans a connected peer for `duration` seconds, then disconnects it.
+    #[method(name = "injector_ban")]
+    async fn ban(&self, peer: RpcPeer, duration: u64) -> RpcResult<()>;
+
+    /// Sends the bytes to a connected peer through the transmitter protocol as-is.
+    #[method(name = "injector_sendRawFrame")]
+    async fn send_raw_frame(&self, peer: RpcPeer, bytes: Hex) -> RpcResult<()>;
+
+    /// Crafts a frame of the transmitter protocol, then sends it to a connected peer.
+    #[method(name = "injector_sendFrame")]
+    async fn send_frame(&self, peer: RpcPeer, frame: TransmitterFrame) -> RpcResult<Hex>;
+}
+
+pub struct NetworkRpcImpl {
+    config: Arc<NetworkConfig>,
+    peer_id: PeerId,
+    peer_manager: Arc<PeerManager>,
+    commander: mpsc::UnboundedSender<NetworkCommand>,
+}
+
+impl NetworkRpcImpl {
+    pub fn new(
+        config: Arc<NetworkConfig>,
+        peer_id: PeerId,
+        peer_manager: Arc<PeerManager>,
+        commander: mpsc::UnboundedSender<NetworkCommand>,
+    ) -> Self {
+        Self {
+            config,
+            peer_id,
+            peer_manager,
+            commander,
+        }
+    }
+}
+
+#[async_trait]
+impl NetworkRpcServer for NetworkRpcImpl {
+    async fn peers(&self) -> RpcResult<Vec<RpcPeerInfo>> {
+        let peers = self.peer_manager.with_registry(|reg| {
+            reg.peers
+                .iter()
+                .map(|(peer_id, peer)| RpcPeerInfo {
+                    peer_id: peer_id.to_base58(),
+                    session_id: peer.session_id.value(),
+                    connected_addr: peer.connected_addr.to_string(),
+                    listened_addrs: peer
+                        .listened_addrs
+                        .iter()
+                        .map(ToString::to_string)
+                        .collect(),
+                    is_inbound: peer.session_type.is_inbound(),
+                    is_feeler: peer.is_feeler,
+                    client_version: peer
+                        .identify_info
+                        .as_ref()
+                        .map(|info| info.client_version.clone()),
+                    ping_rtt: peer.ping_rtt.map(|rtt| rtt.as_millis() as u64),
+                    connected_duration: peer.connected_time.elapsed().as_secs(),
+                })
+                .collect()
+        });
+        Ok(peers)
+    }
+
+    async fn network_status(&self) -> RpcResult<RpcNetworkStatus> {
+        let (status, dialing) = self.peer_manager.with_registry(|reg| {
+            let dialing = reg.dialing.iter().map(ToString::to_string).collect();
+            (reg.connection_status(), dialing)
+        });
+        let network_status = RpcNetworkStatus {
+            peer_id: self.peer_id.to_base58(),
+            listening_address: self.config.default_listen.to_string(),
+            total: status.total,
+            inbound: status.inbound,
+            outbound: status.outbound,
+            max_connections: self.config.max_connections,
+            inbound_conn_limit: self.config.inbound_conn_limit,
+            dialing,
+        };
+        Ok(network_status)
+    }
+
+    async fn dial(&self, addr: Multiaddr) -> RpcResult<()> {
+        if extract_peer_id(&addr).is_none() {
+            let errmsg = format!("failed to extract peer-id from \"{addr}\"");
+            return Err(RpcError::new(-1, errmsg).into());
+        }
+        self.send_command(NetworkCommand::Dial(addr))
+    }
+
+    async fn disconnect(&self, peer: RpcPeer) -> RpcResult<()> {
+        let peer_id = self.connected_peer_id(&peer)?;
+        self.send_command(NetworkCommand::Disconnect(peer_id))
+    }
+
+    async fn ban(&self, peer: RpcPeer, duration: u64) -> RpcResult<()> {
+        let peer_id = self.connected_peer_id(&peer)?;
+        let duration = Duration::from_secs(duration);
+        self.send_command(NetworkCommand::Ban(peer_id, duration))
+    }
+
+    async fn send_raw_frame(&self, peer: RpcPeer, bytes: Hex) -> RpcResult<()> {
+        let peer_id = self.connected_peer_id(&peer)?;
+        self.send_frame_to(peer_id, bytes.as_bytes()).await
+    }
+
+    async fn send_frame(&self, peer: RpcPeer, frame: TransmitterFrame) -> RpcResult<Hex> {
+        let peer_id = self.connected_peer_id(&peer)?;
+        let bytes = frame
+            .encode(max_raw_frame_length(&self.config))
+            .map_err(|e| RpcError::new(-1, e.to_string()))?;
+        self.send_frame_to(peer_id, bytes.clone()).await?;
+        Ok(Hex::encode(bytes))
+    }
+}
+
+impl NetworkRpcImpl {
+    fn connected_peer_id(&self, peer: &RpcPeer) -> RpcResult<PeerId> {
+        let peer_id = peer.peer_id();
+        if self
+            .peer_manager
+            .with_registry(|reg| reg.peers.contains_key(peer_id))
+        {
+            Ok(peer_id.clone())
+        } else {
+            let errmsg = format!("peer {} is not connected", peer_id.to_base58());
+            Err(RpcError::new(-1, errmsg).into())
+        }
+    }
+
+    /// The command is handled by the network service asynchronously.
+    fn send_command(&self, command: NetworkCommand) -> RpcResult<()> {
+        self.commander
+            .send(command)
+            .map_err(|e| RpcError::new(-1, e.to_string()).into())
+    }
+
+    /// Waits until the frame is handed to the session of the peer.
+    async fn send_frame_to(&self, peer_id: PeerId, frame: Bytes) -> RpcResult<()> {
+        let (responder, ret) = oneshot::channel();
+        self.send_command(NetworkCommand::SendFrame(peer_id, frame, responder))?;
+        ret.await
+            .map_err(|e| RpcError::new(-1, e.to_string()))?
+            .map_err(|e| RpcError::new(-1, e.to_string()).into())
+    }
+}
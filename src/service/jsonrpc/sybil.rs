@@ -0,0 +1,117 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use jsonrpsee::{core::RpcResult, proc_macros::rpc};
+use tentacle::secio::PeerId;
+
+use crate::{
+    axon::{
+        core::network::{endpoint::Endpoint, peer_manager::PeerManager, NetworkGossip},
+        protocol::{
+            traits::{Context, Gossip},
+            types::Hex,
+        },
+        services::messages::RawMessage,
+    },
+    result::RpcError,
+};
+
+use super::types::{RpcPriority, RpcSybil};
+
+#[rpc(server)]
+pub trait SybilRpc {
+    /// Returns all extra identities.
+    #[method(name = "injector_sybils")]
+    async fn sybils(&self) -> RpcResult<Vec<RpcSybil>>;
+
+    /// Broadcasts the bytes to an endpoint as-is, through the specified identities.
+    #[method(name = "injector_sybilGossipRaw")]
+    async fn sybil_gossip_raw(
+        &self,
+        names: Option<Vec<String>>,
+        endpoint: String,
+        bytes: Hex,
+        priority: Option<RpcPriority>,
+    ) -> RpcResult<()>;
+}
+
+/// An extra identity in the P2P network.
+pub struct SybilIdentity {
+    pub name: String,
+    pub peer_id: PeerId,
+    pub listening_address: String,
+    pub gossip: NetworkGossip,
+    pub peer_manager: Arc<PeerManager>,
+}
+
+pub struct SybilRpcImpl {
+    sybils: Vec<SybilIdentity>,
+}
+
+impl SybilRpcImpl {
+    pub fn new(sybils: Vec<SybilIdentity>) -> Self {
+        Self { sybils }
+    }
+}
+
+#[async_trait]
+impl SybilRpcServer for SybilRpcImpl {
+    async fn sybils(&self) -> RpcResult<Vec<RpcSybil>> {
+        let sybils = self
+            .sybils
+            .iter()
+            .map(|sybil| RpcSybil {
+                name: sybil.name.clone(),
+                peer_id: sybil.peer_id.to_base58(),
+                listening_address: sybil.listening_address.clone(),
+                connected_peers: sybil.peer_manager.with_registry(|reg| reg.peers.len()),
+            })
+            .collect();
+        Ok(sybils)
+    }
+
+    async fn sybil_gossip_raw(
+        &self,
+        names: Option<Vec<String>>,
+        endpoint: String,
+        bytes: Hex,
+        priority: Option<RpcPriority>,
+    ) -> RpcResult<()> {
+        endpoint
+            .parse::<Endpoint>()
+            .map_err(|e| RpcError::new(-1, e.to_string()))?;
+        if let Some(name) = names
+            .iter()
+            .flatten()
+            .find(|name| !self.sybils.iter().any(|sybil| &sybil.name == *name))
+        {
+            let errmsg = format!("sybil {name:?} is not found");
+            return Err(RpcError::new(-1, errmsg).into());
+        }
+        let bytes = bytes.as_bytes();
+        let pri = priority.unwrap_or_default();
+
+        for sybil in self.sybils.iter().filter(|sybil| {
+            names
+                .as_ref()
+                .map_or(true, |names| names.contains(&sybil.name))
+        }) {
+            log::debug!(
+                "gossip broadcast raw bytes to {endpoint} as {} ...",
+                sybil.name
+            );
+            sybil
+                .gossip
+                .broadcast(
+                    Context::new(),
+                    &endpoint,
+                    RawMessage(bytes.clone()),
+                    pri.into(),
+                )
+                .await
+                .map_err(|e| RpcError::new(-1, e.to_string()))?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,43 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use jsonrpsee::{core::RpcResult, proc_macros::rpc};
+
+use crate::{
+    configs::announcer::AnnouncePlan, result::RpcError, service::announcer::HeightAnnouncer,
+};
+
+#[rpc(server)]
+pub trait SynchronizationRpc {
+    /// Announces fake heights periodically, the previous plan is replaced.
+    #[method(name = "injector_startAnnouncingHeight")]
+    async fn start_announcing_height(&self, plan: AnnouncePlan) -> RpcResult<()>;
+
+    /// Stops announcing fake heights.
+    #[method(name = "injector_stopAnnouncingHeight")]
+    async fn stop_announcing_height(&self) -> RpcResult<()>;
+}
+
+pub struct SynchronizationRpcImpl {
+    announcer: Arc<HeightAnnouncer>,
+}
+
+impl SynchronizationRpcImpl {
+    pub fn new(announcer: Arc<HeightAnnouncer>) -> Self {
+        Self { announcer }
+    }
+}
+
+#[async_trait]
+impl SynchronizationRpcServer for SynchronizationRpcImpl {
+    async fn start_announcing_height(&self, plan: AnnouncePlan) -> RpcResult<()> {
+        self.announcer
+            .start(plan)
+            .map_err(|e| RpcError::new(-1, e.to_string()).into())
+    }
+
+    async fn stop_announcing_height(&self) -> RpcResult<()> {
+        self.announcer.stop();
+        Ok(())
+    }
+}
@@ -0,0 +1,57 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use jsonrpsee::{core::RpcResult, proc_macros::rpc};
+
+use crate::{
+    axon::protocol::types::{SignedTransaction, H256},
+    service::txpool::TxPool,
+};
+
+#[rpc(server)]
+pub trait TxPoolRpc {
+    /// Returns a known transaction, which was injected or received from peers.
+    #[method(name = "injector_getTransactionByHash")]
+    async fn get_transaction_by_hash(&self, hash: H256) -> RpcResult<Option<SignedTransaction>>;
+
+    /// Returns the hashes of all known transactions.
+    #[method(name = "injector_getTransactionHashes")]
+    async fn get_transaction_hashes(&self) -> RpcResult<Vec<H256>>;
+
+    /// Forgets a known transaction, returns whether it was known.
+    #[method(name = "injector_evictTransaction")]
+    async fn evict_transaction(&self, hash: H256) -> RpcResult<bool>;
+
+    /// Forgets all known transactions, returns how many transactions are forgotten.
+    #[method(name = "injector_clearTransactions")]
+    async fn clear_transactions(&self) -> RpcResult<usize>;
+}
+
+pub struct TxPoolRpcImpl {
+    txpool: Arc<TxPool>,
+}
+
+impl TxPoolRpcImpl {
+    pub fn new(txpool: Arc<TxPool>) -> Self {
+        Self { txpool }
+    }
+}
+
+#[async_trait]
+impl TxPoolRpcServer for TxPoolRpcImpl {
+    async fn get_transaction_by_hash(&self, hash: H256) -> RpcResult<Option<SignedTransaction>> {
+        Ok(self.txpool.get(&hash))
+    }
+
+    async fn get_transaction_hashes(&self) -> RpcResult<Vec<H256>> {
+        Ok(self.txpool.hashes())
+    }
+
+    async fn evict_transaction(&self, hash: H256) -> RpcResult<bool> {
+        Ok(self.txpool.remove(&hash).is_some())
+    }
+
+    async fn clear_transactions(&self) -> RpcResult<usize> {
+        Ok(self.txpool.clear())
+    }
+}
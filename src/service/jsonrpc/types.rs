@@ -0,0 +1,59 @@
+use serde::{Deserialize, Serialize};
+
+use crate::axon::protocol::traits::Priority;
+
+#[derive(Clone, Copy, Debug, Default, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RpcPriority {
+    #[default]
+    High,
+    Normal,
+}
+
+impl From<RpcPriority> for Priority {
+    fn from(priority: RpcPriority) -> Self {
+        match priority {
+            RpcPriority::High => Self::High,
+            RpcPriority::Normal => Self::Normal,
+        }
+    }
+}
+
+/// A connected peer.
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RpcPeerInfo {
+    pub peer_id: String,
+    pub session_id: usize,
+    pub connected_addr: String,
+    pub listened_addrs: Vec<String>,
+    pub is_inbound: bool,
+    pub is_feeler: bool,
+    pub client_version: Option<String>,
+    /// Round-trip time of the last ping, in milliseconds.
+    pub ping_rtt: Option<u64>,
+    /// How long the peer has been connected, in seconds.
+    pub connected_duration: u64,
+}
+
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RpcNetworkStatus {
+    pub peer_id: String,
+    pub listening_address: String,
+    pub total: usize,
+    pub inbound: usize,
+    pub outbound: usize,
+    pub max_connections: usize,
+    pub inbound_conn_limit: usize,
+    pub dialing: Vec<String>,
+}
+
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RpcSybil {
+    pub name: String,
+    pub peer_id: String,
+    pub listening_address: String,
+    pub connected_peers: usize,
+}
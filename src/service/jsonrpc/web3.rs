@@ -1,3 +1,5 @@
+use std::sync::Arc;
+
 use async_trait::async_trait;
 use jsonrpsee::{core::RpcResult, proc_macros::rpc};
 
@@ -12,6 +14,7 @@ use crate::{
         services::endpoints::mempool::END_GOSSIP_NEW_TXS,
     },
     result::RpcError,
+    service::txpool::TxPool,
 };
 
 #[rpc(server)]
@@ -22,11 +25,12 @@ pub trait Web3Rpc {
 
 pub struct Web3RpcImpl {
     gossip: NetworkGossip,
+    txpool: Arc<TxPool>,
 }
 
 impl Web3RpcImpl {
-    pub fn new(gossip: NetworkGossip) -> Self {
-        Self { gossip }
+    pub fn new(gossip: NetworkGossip, txpool: Arc<TxPool>) -> Self {
+        Self { gossip, txpool }
     }
 }
 
@@ -35,12 +39,9 @@ impl Web3RpcServer for Web3RpcImpl {
     async fn broadcast_transaction(&self, tx: Hex) -> RpcResult<H256> {
         let ctx = Context::new();
         let ep = END_GOSSIP_NEW_TXS;
-        let tx_bytes = tx.as_bytes();
-        let utx = UnverifiedTransaction::decode(&tx_bytes)
-            .map_err(|e| RpcError::new(-1, e.to_string()))?;
-        let stx = SignedTransaction::from_unverified(utx)
-            .map_err(|e| RpcError::new(-1, e.to_string()))?;
+        let stx = decode_signed_transaction(&tx)?;
         let tx_hash = stx.transaction.hash;
+        self.txpool.insert(stx.clone());
         let stxs = BatchSignedTxs::new(vec![stx]);
         let pri = Priority::High;
 
@@ -53,3 +54,12 @@ impl Web3RpcServer for Web3RpcImpl {
         Ok(tx_hash)
     }
 }
+
+pub(super) fn decode_signed_transaction(tx: &Hex) -> RpcResult<SignedTransaction> {
+    let tx_bytes = tx.as_bytes();
+    let utx =
+        UnverifiedTransaction::decode(&tx_bytes).map_err(|e| RpcError::new(-1, e.to_string()))?;
+    let stx =
+        SignedTransaction::from_unverified(utx).map_err(|e| RpcError::new(-1, e.to_string()))?;
+    Ok(stx)
+}
@@ -1,29 +1,53 @@
-use std::time::Duration;
+use std::{future::Future, sync::Arc, time::Duration};
 
-use tokio::runtime::Builder as RuntimeBuilder;
+use tokio::{runtime::Builder as RuntimeBuilder, time::sleep};
 
 use crate::{
+    axon::core::network::{peer_manager::PeerManager, NetworkGossip},
+    builders::nonce::NonceManager,
     configs::serve::Config,
     result::{Error, Result},
 };
 
+pub mod announcer;
 pub mod jsonrpc;
 pub mod network;
+mod replay;
+mod send;
+pub mod txpool;
 
+use announcer::HeightAnnouncer;
 use jsonrpc::JsonrpcService;
 use network::NetworkService;
 
+pub use replay::ReplayService;
+pub use send::SendService;
+
 pub struct BroadcastService {
     network: NetworkService,
+    sybils: Vec<NetworkService>,
     jsonrpc: JsonrpcService,
+    announcer: Arc<HeightAnnouncer>,
 }
 
 impl BroadcastService {
     pub fn new(config: &Config) -> Result<Self> {
         let network = NetworkService::new(config)?;
-        let gossip = network.gossip().clone();
-        let jsonrpc = JsonrpcService::new(config, gossip)?;
-        let service = Self { network, jsonrpc };
+        let nonces = NonceManager::load(&config.nonces_file()).map(Arc::new)?;
+        let announcer = HeightAnnouncer::new(config.height_announcer()).map(Arc::new)?;
+        let sybils = config
+            .sybils()
+            .iter()
+            .map(|sybil| NetworkService::new_sybil(config, sybil, Arc::clone(network.txpool())))
+            .collect::<Result<Vec<_>>>()?;
+        let jsonrpc =
+            JsonrpcService::new(config, &network, &sybils, nonces, Arc::clone(&announcer))?;
+        let service = Self {
+            network,
+            sybils,
+            jsonrpc,
+            announcer,
+        };
         Ok(service)
     }
 
@@ -31,10 +55,23 @@ impl BroadcastService {
         let rt = RuntimeBuilder::new_multi_thread().enable_all().build()?;
         let timeout = Duration::from_secs(100);
 
+        let gossip = self.network.gossip().clone();
         let network = rt.spawn(async move {
             log::info!("Start Network service ...");
             self.network.start().await
         });
+        for sybil in self.sybils {
+            rt.spawn(async move {
+                log::info!("Start Network service of a sybil ...");
+                if let Err(err) = sybil.start().await {
+                    log::error!("Network service of a sybil stopped since {err}");
+                }
+            });
+        }
+        rt.spawn(async move {
+            log::info!("Start Height announcer ...");
+            self.announcer.run(gossip).await
+        });
         let jsonrpc = rt.spawn(async move {
             log::info!("Start Jsonrpc service ...");
             self.jsonrpc.start().await
@@ -50,3 +87,55 @@ impl BroadcastService {
         Ok(())
     }
 }
+
+/// Runs a task with the network service, and stops the network service once the task is done.
+///
+/// The task doesn't start until there is at least one connected peer.
+fn run_with_network<F, Fut>(network: NetworkService, task: F) -> Result<()>
+where
+    F: FnOnce(NetworkGossip) -> Fut,
+    Fut: Future<Output = Result<()>>,
+{
+    let rt = RuntimeBuilder::new_multi_thread().enable_all().build()?;
+    let timeout = Duration::from_secs(100);
+
+    let gossip = network.gossip().clone();
+    let peer_manager = Arc::clone(network.peer_manager());
+    let mut network = rt.spawn(async move {
+        log::info!("Start Network service ...");
+        network.start().await
+    });
+
+    rt.block_on(async {
+        tokio::select! {
+            ret = &mut network => {
+                ret??;
+                let errmsg = "network service stopped before the task finished".to_owned();
+                Err(Error::Network(errmsg))
+            }
+            ret = async {
+                wait_for_peers(&peer_manager).await;
+                let ret = task(gossip).await;
+                // Give the network service a chance to send out the pending messages.
+                sleep(Duration::from_secs(1)).await;
+                ret
+            } => ret,
+        }
+    })?;
+    network.abort();
+    rt.shutdown_timeout(timeout);
+
+    Ok(())
+}
+
+async fn wait_for_peers(peer_manager: &PeerManager) {
+    log::info!("Wait for connected peers ...");
+    loop {
+        let count = peer_manager.with_registry(|reg| reg.peers.len());
+        if count > 0 {
+            log::info!("There are {count} connected peers");
+            break;
+        }
+        sleep(Duration::from_secs(1)).await;
+    }
+}
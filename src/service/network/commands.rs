@@ -0,0 +1,89 @@
+use std::time::Duration;
+
+use tentacle::{bytes::Bytes, multiaddr::Multiaddr, secio::PeerId, SessionId};
+use tokio::sync::oneshot;
+
+use crate::{
+    axon::core::network::protocols::SupportProtocols,
+    result::{Error, Result},
+};
+
+use super::{protocols::transmitter::max_raw_frame_length, NetworkService};
+
+/// Commands to steer the network service at runtime.
+#[derive(Debug)]
+pub enum NetworkCommand {
+    Dial(Multiaddr),
+    Disconnect(PeerId),
+    /// Bans the connected address of a peer, then disconnects it.
+    Ban(PeerId, Duration),
+    /// Sends the bytes through the transmitter protocol as-is,
+    /// then reports whether the bytes are handed to the session.
+    SendFrame(PeerId, Bytes, oneshot::Sender<Result<()>>),
+}
+
+impl NetworkService {
+    pub(crate) async fn handle_command(&mut self, command: NetworkCommand) -> Result<()> {
+        log::debug!("handle network command {command:?}");
+        match command {
+            NetworkCommand::Dial(addr) => self.dial_identify(addr).await,
+            NetworkCommand::Disconnect(peer_id) => self.disconnect(&peer_id).await,
+            NetworkCommand::Ban(peer_id, duration) => {
+                let addr = self
+                    .peer_manager()
+                    .with_registry(|reg| {
+                        reg.peers
+                            .get(&peer_id)
+                            .map(|peer| peer.connected_addr.clone())
+                    })
+                    .ok_or_else(|| {
+                        let errmsg = format!("peer {} is not connected", peer_id.to_base58());
+                        Error::Network(errmsg)
+                    })?;
+                let duration_ms = u64::try_from(duration.as_millis()).unwrap_or(u64::MAX);
+                self.peer_manager().with_peer_store_mut(|peer_store| {
+                    let reason = "banned through JSON-RPC".to_owned();
+                    peer_store.ban_addr(&addr, duration_ms, reason)
+                });
+                self.disconnect(&peer_id).await
+            }
+            NetworkCommand::SendFrame(peer_id, frame, responder) => {
+                let ret = self.send_frame(&peer_id, frame).await;
+                let _ignore = responder.send(ret);
+                Ok(())
+            }
+        }
+    }
+
+    async fn send_frame(&self, peer_id: &PeerId, frame: Bytes) -> Result<()> {
+        let max_length = max_raw_frame_length(self.config());
+        if frame.len() > max_length {
+            let errmsg = format!(
+                "the frame length {} exceeds the limit {max_length}",
+                frame.len()
+            );
+            return Err(Error::Network(errmsg));
+        }
+        let session_id = self.session_id(peer_id)?;
+        let protocol = SupportProtocols::Transmitter.protocol_id();
+        self.control()
+            .send_message_to(session_id, protocol, frame)
+            .await?;
+        Ok(())
+    }
+
+    async fn disconnect(&self, peer_id: &PeerId) -> Result<()> {
+        let session_id = self.session_id(peer_id)?;
+        self.control().disconnect(session_id).await?;
+        Ok(())
+    }
+
+    fn session_id(&self, peer_id: &PeerId) -> Result<SessionId> {
+        self.peer_manager()
+            .with_registry(|reg| reg.peers.get(peer_id).map(|peer| peer.session_id))
+            .ok_or_else(|| {
+                let errmsg = format!("peer {} is not connected", peer_id.to_base58());
+                Error::Network(errmsg)
+            })
+    }
+}
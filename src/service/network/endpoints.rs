@@ -1,87 +1,153 @@
-use std::marker::{PhantomData, Sync};
+use std::{
+    collections::HashSet,
+    marker::{PhantomData, Sync},
+    sync::Arc,
+};
 
 use async_trait::async_trait;
 
 use crate::{
     axon::{
         core::network::endpoint::Endpoint,
-        protocol::{
-            traits::{Context, MessageCodec, MessageHandler, TrustFeedback},
-            types::Bytes,
-            ProtocolResult,
-        },
-        services::endpoints,
+        protocol::traits::{Context, MessageCodec, MessageHandler, TrustFeedback},
+        services::{endpoints, messages, messages::mempool::BatchSignedTxs},
     },
+    capture::{Record, Recorder},
+    configs::{feedback::Feedback, responders::ResponderConfig},
     result::{Error, Result},
+    service::txpool::TxPool,
 };
 
-use super::NetworkService;
+use super::{
+    responders::{PullTxs, Respond, Responder, SyncPullBlock, SyncPullProof, SyncPullTxs},
+    NetworkService,
+};
 
 pub struct IgnoredMessageHandler<M> {
     endpoint: Endpoint,
+    recorder: Option<Arc<Recorder>>,
+    feedback: Feedback,
     phantom: PhantomData<M>,
 }
 
-#[derive(Debug)]
-pub struct IgnoredMessage;
-
 #[async_trait]
 impl<M: MessageCodec + Sync> MessageHandler for IgnoredMessageHandler<M> {
     type Message = M;
-    async fn process(&self, _ctx: Context, _msg: Self::Message) -> TrustFeedback {
-        log::trace!("ignore a message on endpoint {}", self.endpoint);
-        TrustFeedback::Neutral
+    async fn process(&self, ctx: Context, mut msg: Self::Message) -> TrustFeedback {
+        if let Some(recorder) = self.recorder.as_ref() {
+            record_message(recorder, &ctx, &self.endpoint, &mut msg);
+        } else {
+            log::trace!("ignore a message on endpoint {}: {msg:?}", self.endpoint);
+        }
+        self.feedback.clone().into()
     }
 }
 
-impl MessageCodec for IgnoredMessage {
-    fn encode_msg(&mut self) -> ProtocolResult<Bytes> {
-        Ok(Default::default())
+impl<M: MessageCodec> IgnoredMessageHandler<M> {
+    pub fn new(endpoint: Endpoint, recorder: Option<Arc<Recorder>>, feedback: Feedback) -> Self {
+        Self {
+            endpoint,
+            recorder,
+            feedback,
+            phantom: PhantomData,
+        }
     }
+}
 
-    fn decode_msg(_bytes: Bytes) -> ProtocolResult<Self> {
-        Ok(Self)
+/// Remembers all transactions which are broadcasted by peers.
+pub struct NewTxsHandler {
+    endpoint: Endpoint,
+    recorder: Option<Arc<Recorder>>,
+    feedback: Feedback,
+    txpool: Arc<TxPool>,
+}
+
+#[async_trait]
+impl MessageHandler for NewTxsHandler {
+    type Message = BatchSignedTxs;
+    async fn process(&self, ctx: Context, mut msg: Self::Message) -> TrustFeedback {
+        if let Some(recorder) = self.recorder.as_ref() {
+            record_message(recorder, &ctx, &self.endpoint, &mut msg);
+        }
+        let stxs = msg.inner();
+        log::trace!("receive {} transactions from peers", stxs.len());
+        for stx in stxs {
+            self.txpool.insert(stx);
+        }
+        self.feedback.clone().into()
     }
 }
 
-impl<M: MessageCodec> IgnoredMessageHandler<M> {
-    pub fn new(endpoint: Endpoint) -> Self {
+impl NewTxsHandler {
+    pub fn new(
+        endpoint: Endpoint,
+        recorder: Option<Arc<Recorder>>,
+        feedback: Feedback,
+        txpool: Arc<TxPool>,
+    ) -> Self {
         Self {
             endpoint,
-            phantom: PhantomData,
+            recorder,
+            feedback,
+            txpool,
         }
     }
 }
 
+/// Records the message re-encoded, since the received bytes are not available here.
+pub(crate) fn record_message<M: MessageCodec>(
+    recorder: &Recorder,
+    ctx: &Context,
+    endpoint: &Endpoint,
+    msg: &mut M,
+) {
+    let _ignore = msg
+        .encode_msg()
+        .map_err(Into::into)
+        .and_then(|bytes| {
+            let record = Record::new(ctx, endpoint, bytes);
+            recorder.append(record)
+        })
+        .map_err(|err| log::warn!("failed to record a message on endpoint {endpoint} since {err}"));
+}
+
 impl NetworkService {
     pub(crate) fn register_endpoints(&self) -> Result<()> {
+        let mut registered = HashSet::new();
         macro_rules! ignore_endpoint {
             ($self:ident, $domain:ident, $endpoint:ident, $message:ident) => {
-                /* TODO
+                registered.insert(endpoints::$domain::$endpoint);
                 self.register_ignored_endpoint::<messages::$domain::$message>(
                     endpoints::$domain::$endpoint,
                 )?;
-                */
-                self.register_ignored_endpoint::<IgnoredMessage>(endpoints::$domain::$endpoint)?;
             };
+            // RPC responses never return feedbacks, so they are not allowed in feedbacks
             ($self:ident, $domain:ident, $endpoint:ident) => {
                 self.register_ignored_rpc_endpoint(endpoints::$domain::$endpoint)?;
             };
         }
+        let responders = self.responders().clone();
+
         //
         // Mempool
         //
         // broadcast new transaction
-        ignore_endpoint!(self, mempool, END_GOSSIP_NEW_TXS, BatchSignedTxs);
+        registered.insert(endpoints::mempool::END_GOSSIP_NEW_TXS);
+        self.register_new_txs_endpoint(endpoints::mempool::END_GOSSIP_NEW_TXS)?;
         // pull txs from other node
-        ignore_endpoint!(self, mempool, RPC_PULL_TXS, MsgPullTxs);
+        registered.insert(endpoints::mempool::RPC_PULL_TXS);
+        self.register_responder(
+            endpoints::mempool::RPC_PULL_TXS,
+            responders.pull_txs,
+            PullTxs::new(Arc::clone(self.txpool())),
+        )?;
 
         //
         // Consensus
         //
         ignore_endpoint!(self, consensus, END_GOSSIP_SIGNED_PROPOSAL, Proposal);
-        ignore_endpoint!(self, consensus, END_GOSSIP_AGGREGATED_VOTE, Vote);
-        ignore_endpoint!(self, consensus, END_GOSSIP_SIGNED_VOTE, QC);
+        ignore_endpoint!(self, consensus, END_GOSSIP_SIGNED_VOTE, Vote);
+        ignore_endpoint!(self, consensus, END_GOSSIP_AGGREGATED_VOTE, QC);
         ignore_endpoint!(self, consensus, END_GOSSIP_SIGNED_CHOKE, Choke);
 
         //
@@ -92,9 +158,24 @@ impl NetworkService {
         //
         // Storage
         //
-        ignore_endpoint!(self, storage, RPC_SYNC_PULL_BLOCK, BlockNumber);
-        ignore_endpoint!(self, storage, RPC_SYNC_PULL_PROOF, BlockNumber);
-        ignore_endpoint!(self, storage, RPC_SYNC_PULL_TXS, PullTxsRequest);
+        registered.insert(endpoints::storage::RPC_SYNC_PULL_BLOCK);
+        self.register_responder(
+            endpoints::storage::RPC_SYNC_PULL_BLOCK,
+            responders.sync_pull_block,
+            SyncPullBlock::new(self.chain_id()),
+        )?;
+        registered.insert(endpoints::storage::RPC_SYNC_PULL_PROOF);
+        self.register_responder(
+            endpoints::storage::RPC_SYNC_PULL_PROOF,
+            responders.sync_pull_proof,
+            SyncPullProof,
+        )?;
+        registered.insert(endpoints::storage::RPC_SYNC_PULL_TXS);
+        self.register_responder(
+            endpoints::storage::RPC_SYNC_PULL_TXS,
+            responders.sync_pull_txs,
+            SyncPullTxs::new(Arc::clone(self.txpool())),
+        )?;
 
         //
         // JSON-RPC (consensus)
@@ -109,6 +190,13 @@ impl NetworkService {
         ignore_endpoint!(self, jsonrpc, RPC_RESP_PULL_TXS);
         ignore_endpoint!(self, jsonrpc, RPC_RESP_PULL_TXS_SYNC);
 
+        for endpoint_str in self.feedbacks.keys() {
+            if !registered.contains(endpoint_str.as_str()) {
+                let errmsg = format!("endpoint {endpoint_str:?} of feedbacks is not registered");
+                return Err(Error::Network(errmsg));
+            }
+        }
+
         Ok(())
     }
 
@@ -117,7 +205,52 @@ impl NetworkService {
             let errmsg = format!("failed to parse endpoint {endpoint_str:?} since {err}");
             Error::Network(errmsg)
         })?;
-        let handler = IgnoredMessageHandler::<M>::new(endpoint.clone());
+        let handler = IgnoredMessageHandler::<M>::new(
+            endpoint.clone(),
+            self.recorder().cloned(),
+            self.feedback(endpoint_str),
+        );
+        self.message_router().register_reactor(endpoint, handler);
+        Ok(())
+    }
+
+    fn register_new_txs_endpoint(&self, endpoint_str: &str) -> Result<()> {
+        let endpoint = endpoint_str.parse::<Endpoint>().map_err(|err| {
+            let errmsg = format!("failed to parse endpoint {endpoint_str:?} since {err}");
+            Error::Network(errmsg)
+        })?;
+        let handler = NewTxsHandler::new(
+            endpoint.clone(),
+            self.recorder().cloned(),
+            self.feedback(endpoint_str),
+            Arc::clone(self.txpool()),
+        );
+        self.message_router().register_reactor(endpoint, handler);
+        Ok(())
+    }
+
+    /// Registers a responder for the endpoint, or ignores it if there is no config.
+    fn register_responder<R: Respond>(
+        &self,
+        endpoint_str: &str,
+        config: Option<ResponderConfig>,
+        inner: R,
+    ) -> Result<()> {
+        let Some(config) = config else {
+            return self.register_ignored_endpoint::<R::Request>(endpoint_str);
+        };
+        let endpoint = endpoint_str.parse::<Endpoint>().map_err(|err| {
+            let errmsg = format!("failed to parse endpoint {endpoint_str:?} since {err}");
+            Error::Network(errmsg)
+        })?;
+        let handler = Responder::new(
+            endpoint.clone(),
+            self.rpc().clone(),
+            config,
+            self.recorder().cloned(),
+            self.feedback(endpoint_str),
+            inner,
+        );
         self.message_router().register_reactor(endpoint, handler);
         Ok(())
     }
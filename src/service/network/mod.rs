@@ -1,35 +1,60 @@
-use std::{ops::Deref, sync::Arc, time::Duration};
+use std::{collections::HashMap, ops::Deref, sync::Arc, time::Duration};
 
 use tentacle::{
     builder::ServiceBuilder,
-    secio::SecioKeyPair,
+    secio::{PeerId, SecioKeyPair},
     service::{HandshakeType, ProtocolMeta, Service, ServiceAsyncControl, TcpSocket},
     utils::multiaddr_to_socketaddr,
     yamux::Config as YamuxConfig,
 };
-use tokio::time::{interval_at, Instant, MissedTickBehavior};
+use tokio::{
+    sync::mpsc,
+    time::{interval_at, Instant, MissedTickBehavior},
+};
 
 use crate::{
-    axon::core::network::{
-        peer_manager::PeerManager, reactor::MessageRouter, KeyProvider, NetworkConfig,
-        NetworkGossip, ServiceHandler,
+    axon::{
+        core::network::{
+            peer_manager::PeerManager, reactor::MessageRouter, KeyProvider, NetworkConfig,
+            NetworkGossip, NetworkRpc, ServiceHandler,
+        },
+        protocol::types::Key256Bits,
+    },
+    capture::Recorder,
+    configs::{
+        feedback::Feedback,
+        responders::Responders,
+        serve::{Config, Sybil},
     },
-    configs::serve::Config,
     result::{Error, Result},
+    service::txpool::TxPool,
 };
 
+pub mod commands;
 mod endpoints;
-mod protocols;
+pub mod protocols;
+pub mod responders;
+
+use commands::NetworkCommand;
 
 const MAX_STREAM_WINDOW_SIZE: u32 = 1024 * 1024;
 
 pub struct NetworkService {
     config: Arc<NetworkConfig>,
+    chain_id: u64,
+    peer_id: PeerId,
     gossip: NetworkGossip,
+    rpc: NetworkRpc,
 
     peer_manager: Arc<PeerManager>,
     message_router: MessageRouter,
     control: ServiceAsyncControl,
+    recorder: Option<Arc<Recorder>>,
+    responders: Responders,
+    feedbacks: HashMap<String, Feedback>,
+    txpool: Arc<TxPool>,
+    commander: mpsc::UnboundedSender<NetworkCommand>,
+    commands: Option<mpsc::UnboundedReceiver<NetworkCommand>>,
 
     internal: Option<Service<ServiceHandler, SecioKeyPair>>,
     try_identify_count: u8,
@@ -40,19 +65,52 @@ pub struct NetworkService {
 //
 impl NetworkService {
     pub fn new(raw_config: &Config) -> Result<Self> {
-        let config = {
-            let network_config: NetworkConfig = raw_config.network()?;
-            Arc::new(network_config)
-        };
+        let network_config = raw_config.network()?;
+        let recorder = raw_config
+            .capture_file()
+            .map(|file_path| Recorder::open(&file_path).map(Arc::new))
+            .transpose()?;
+        Self::build(
+            raw_config,
+            network_config,
+            raw_config.network_key(),
+            recorder,
+            Arc::new(TxPool::new(raw_config.txpool_capacity())),
+        )
+    }
+
+    /// Creates an extra identity, which doesn't capture messages and shares the transactions
+    /// with the primary identity.
+    pub fn new_sybil(raw_config: &Config, sybil: &Sybil, txpool: Arc<TxPool>) -> Result<Self> {
+        let network_config = raw_config.sybil_network(sybil)?;
+        Self::build(raw_config, network_config, &sybil.key, None, txpool)
+    }
+
+    fn build(
+        raw_config: &Config,
+        network_config: NetworkConfig,
+        network_key: &Key256Bits,
+        recorder: Option<Arc<Recorder>>,
+        txpool: Arc<TxPool>,
+    ) -> Result<Self> {
+        let config = Arc::new(network_config);
         let peer_manager = {
             let peer_manager = PeerManager::new(Arc::clone(&config));
             Arc::new(peer_manager)
         };
         let message_router = MessageRouter::new();
+        let key_pair = SecioKeyPair::secp256k1_raw_key(network_key.deref())?;
+        let peer_id = key_pair.public_key().peer_id();
         let service = {
             let message_router = message_router.clone();
-            let protocol_metas = Self::build_protocol_metas(&config, &peer_manager, message_router);
-            let key_provider = SecioKeyPair::secp256k1_raw_key(raw_config.network_key().deref())?;
+            let protocol_metas = Self::build_protocol_metas(
+                &config,
+                &peer_manager,
+                message_router,
+                raw_config.chain_id(),
+                &raw_config.protocols(),
+            );
+            let key_provider = key_pair;
             let service_builder =
                 initialize_service_builder(&config, protocol_metas, key_provider)?;
             let peer_store = Arc::clone(&peer_manager);
@@ -65,22 +123,40 @@ impl NetworkService {
             let control = service.control().clone();
             NetworkGossip::new(control, Arc::clone(&peer_manager))
         };
-        Ok(Self {
+        let rpc = {
+            let control = service.control().clone();
+            NetworkRpc::new(control, message_router.rpc_map(), (&*config).into())
+        };
+        let (commander, commands) = mpsc::unbounded_channel();
+        let service = Self {
             config,
+            chain_id: raw_config.chain_id(),
+            peer_id,
             gossip,
+            rpc,
             peer_manager,
             message_router,
             control,
+            recorder,
+            responders: raw_config.responders(),
+            feedbacks: raw_config.feedbacks(),
+            txpool,
+            commander,
+            commands: Some(commands),
             internal: Some(service),
             try_identify_count: 0,
-        })
+        };
+        service.register_endpoints()?;
+        Ok(service)
     }
 
     pub async fn start(mut self) -> Result<()> {
-        self.register_endpoints()?;
-
         let control = self.control().clone();
         let peer_manager = Arc::clone(self.peer_manager());
+        let mut commands = self.commands.take().ok_or_else(|| {
+            let errmsg = "network service has been started".to_owned();
+            Error::Network(errmsg)
+        })?;
         if let Some(mut service) = self.internal.take() {
             service.listen(self.config.default_listen.clone()).await?;
             for addr in self.config.bootstraps.clone() {
@@ -101,6 +177,10 @@ impl NetworkService {
                         self.try_dial_peers().await?;
                         self.try_dial_feeler().await?;
                     }
+                    Some(command) = commands.recv() => {
+                        let _ignore = self.handle_command(command).await
+                            .map_err(|err| log::warn!("failed to handle a network command since {err}"));
+                    }
                     _ = dump_interval.tick() => {
                         peer_manager.with_peer_store(|store|{
                             let _ignore = store.dump_to_dir(self.config.peer_store_path.clone())
@@ -127,7 +207,23 @@ impl NetworkService {
         &self.gossip
     }
 
-    fn peer_manager(&self) -> &Arc<PeerManager> {
+    pub(crate) fn config(&self) -> &Arc<NetworkConfig> {
+        &self.config
+    }
+
+    pub(crate) fn peer_id(&self) -> &PeerId {
+        &self.peer_id
+    }
+
+    pub(crate) fn commander(&self) -> &mpsc::UnboundedSender<NetworkCommand> {
+        &self.commander
+    }
+
+    pub(crate) fn txpool(&self) -> &Arc<TxPool> {
+        &self.txpool
+    }
+
+    pub(crate) fn peer_manager(&self) -> &Arc<PeerManager> {
         &self.peer_manager
     }
 
@@ -138,6 +234,29 @@ impl NetworkService {
     fn control(&self) -> &ServiceAsyncControl {
         &self.control
     }
+
+    fn recorder(&self) -> Option<&Arc<Recorder>> {
+        self.recorder.as_ref()
+    }
+
+    fn chain_id(&self) -> u64 {
+        self.chain_id
+    }
+
+    fn rpc(&self) -> &NetworkRpc {
+        &self.rpc
+    }
+
+    fn responders(&self) -> &Responders {
+        &self.responders
+    }
+
+    fn feedback(&self, endpoint_str: &str) -> Feedback {
+        self.feedbacks
+            .get(endpoint_str)
+            .cloned()
+            .unwrap_or_default()
+    }
 }
 
 fn initialize_service_builder<K: KeyProvider>(
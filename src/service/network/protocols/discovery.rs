@@ -0,0 +1,77 @@
+use rand::Rng as _;
+use tentacle::{
+    multiaddr::{Multiaddr, Protocol},
+    secio::PeerId,
+    ProtocolId, SessionId,
+};
+
+use crate::{
+    axon::core::network::protocols::{
+        AddressManager, DiscoveryAddressManager, MisbehaveResult, Misbehavior,
+    },
+    configs::protocols::PoisonedAddresses,
+};
+
+/// An address manager which answers `GetNodes` requests with poisoned addresses,
+/// whatever how many addresses are requested.
+///
+/// Others are delegated to the standard address manager, so the local peer store still works.
+pub struct PoisonedAddressManager {
+    inner: DiscoveryAddressManager,
+    poisoned: PoisonedAddresses,
+}
+
+impl PoisonedAddressManager {
+    pub fn new(inner: DiscoveryAddressManager, poisoned: PoisonedAddresses) -> Self {
+        Self { inner, poisoned }
+    }
+}
+
+impl AddressManager for PoisonedAddressManager {
+    fn register(&self, id: SessionId, pid: ProtocolId, version: &str) {
+        self.inner.register(id, pid, version)
+    }
+
+    fn unregister(&self, id: SessionId, pid: ProtocolId) {
+        self.inner.unregister(id, pid)
+    }
+
+    fn is_valid_addr(&self, addr: &Multiaddr) -> bool {
+        self.inner.is_valid_addr(addr)
+    }
+
+    fn add_new_addr(&mut self, session_id: SessionId, addr: Multiaddr) {
+        self.inner.add_new_addr(session_id, addr)
+    }
+
+    fn add_new_addrs(&mut self, session_id: SessionId, addrs: Vec<Multiaddr>) {
+        self.inner.add_new_addrs(session_id, addrs)
+    }
+
+    fn misbehave(&mut self, session_id: SessionId, kind: &Misbehavior) -> MisbehaveResult {
+        self.inner.misbehave(session_id, kind)
+    }
+
+    fn get_random(&mut self, n: usize) -> Vec<Multiaddr> {
+        let total = self.poisoned.addresses.len() + self.poisoned.random;
+        log::trace!("advertise {total} poisoned addresses, while {n} addresses are requested");
+        let mut rng = rand::thread_rng();
+        let randoms = (0..self.poisoned.random).map(|_| {
+            let ip = rng.gen::<[u8; 4]>().into();
+            let port = rng.gen_range(1024..=u16::MAX);
+            [
+                Protocol::Ip4(ip),
+                Protocol::Tcp(port),
+                Protocol::P2P(PeerId::random().into_bytes().into()),
+            ]
+            .into_iter()
+            .collect::<Multiaddr>()
+        });
+        self.poisoned
+            .addresses
+            .iter()
+            .cloned()
+            .chain(randoms)
+            .collect()
+    }
+}
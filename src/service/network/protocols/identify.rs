@@ -0,0 +1,106 @@
+//! A misbehaving identify protocol, which advertises spoofed data.
+//!
+//! The identify message is mirrored from `core-network` of Axon, which is encoded by protobuf:
+//!
+//! ```protobuf
+//! message IdentifyMessage {
+//!     repeated bytes listen_addrs = 1;
+//!     bytes observed_addr = 2;
+//!     Identity identify = 3;
+//! }
+//! message Identity {
+//!     uint64 chain_id = 1;
+//!     string client_version = 2;
+//!     repeated string protocols = 3;
+//! }
+//! ```
+
+use async_trait::async_trait;
+use tentacle::{
+    bytes::{Bytes, BytesMut},
+    context::{ProtocolContext, ProtocolContextMutRef},
+    multiaddr::Multiaddr,
+    traits::ServiceProtocol,
+};
+
+use crate::configs::protocols::SpoofedIdentity;
+
+use super::protobuf::{put_bytes_field, put_varint_field};
+
+/// Sends a spoofed identify message once a session is opened, and ignores all received messages.
+///
+/// The local node never treats the remote peers as identified, so only the remote sides are tested.
+pub struct SpoofedIdentifyProtocol {
+    chain_id: u64,
+    client_version: String,
+    protocols: Vec<String>,
+    listen_addrs: Vec<Multiaddr>,
+    observed_addr: Option<Multiaddr>,
+}
+
+impl SpoofedIdentifyProtocol {
+    pub fn new(
+        spoofed: SpoofedIdentity,
+        chain_id: u64,
+        protocols: Vec<String>,
+        listen_addr: Multiaddr,
+    ) -> Self {
+        Self {
+            chain_id: spoofed.chain_id.unwrap_or(chain_id),
+            client_version: spoofed.client_version.unwrap_or_else(|| {
+                format!("{} {}", env!("CARGO_PKG_NAME"), env!("CARGO_PKG_VERSION"))
+            }),
+            protocols: spoofed.protocols.unwrap_or(protocols),
+            listen_addrs: spoofed.listen_addrs.unwrap_or_else(|| vec![listen_addr]),
+            observed_addr: spoofed.observed_addr,
+        }
+    }
+
+    fn encode(&self, observed_addr: &Multiaddr) -> Bytes {
+        let mut identity = BytesMut::new();
+        put_varint_field(&mut identity, 1, self.chain_id);
+        put_bytes_field(&mut identity, 2, self.client_version.as_bytes());
+        for protocol in &self.protocols {
+            put_bytes_field(&mut identity, 3, protocol.as_bytes());
+        }
+
+        let mut msg = BytesMut::new();
+        for addr in &self.listen_addrs {
+            put_bytes_field(&mut msg, 1, &addr.to_vec());
+        }
+        put_bytes_field(&mut msg, 2, &observed_addr.to_vec());
+        put_bytes_field(&mut msg, 3, &identity);
+        msg.freeze()
+    }
+}
+
+#[async_trait]
+impl ServiceProtocol for SpoofedIdentifyProtocol {
+    async fn init(&mut self, _context: &mut ProtocolContext) {}
+
+    async fn connected(&mut self, context: ProtocolContextMutRef<'_>, _version: &str) {
+        let observed_addr = self
+            .observed_addr
+            .clone()
+            .unwrap_or_else(|| context.session.address.clone());
+        let msg = self.encode(&observed_addr);
+        log::trace!(
+            "send a spoofed identify message to session {}",
+            context.session.id
+        );
+        if let Err(err) = context.send_message(msg).await {
+            log::warn!(
+                "failed to send a spoofed identify message to session {} since {err}",
+                context.session.id
+            );
+        }
+    }
+
+    async fn received(&mut self, context: ProtocolContextMutRef<'_>, data: Bytes) {
+        log::trace!(
+            "spoofed identify protocol ignores {} bytes from session {}",
+            data.len(),
+            context.session.id
+        );
+    }
+}
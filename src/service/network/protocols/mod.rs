@@ -0,0 +1,320 @@
+use std::{sync::Arc, time::Duration};
+
+use rand::{self, seq::IteratorRandom};
+use tentacle::{
+    builder::MetaBuilder,
+    multiaddr::Multiaddr,
+    service::{ProtocolHandle, ProtocolMeta, TargetProtocol},
+    traits::ServiceProtocol,
+    utils::extract_peer_id,
+};
+use tokio_util::codec::length_delimited;
+
+use crate::{
+    axon::core::network::{
+        peer_manager::{AddrInfo, PeerManager, PeerStore},
+        protocols::{
+            DiscoveryAddressManager, DiscoveryProtocol, Feeler, IdentifyProtocol, PingHandler,
+            SupportProtocols, TransmitterProtocol,
+        },
+        reactor::MessageRouter,
+        NetworkConfig,
+    },
+    configs::protocols::{DiscoveryMode, FeelerMode, IdentifyMode, PingMode, Protocols},
+    result::{Error, Result},
+};
+
+use super::NetworkService;
+
+mod discovery;
+mod identify;
+mod ping;
+mod protobuf;
+mod silent;
+pub mod transmitter;
+
+use discovery::PoisonedAddressManager;
+use identify::SpoofedIdentifyProtocol;
+use ping::{MisbehavingPingProtocol, PingMisbehavior};
+use silent::SilentProtocol;
+use transmitter::max_raw_frame_length;
+
+/// Names of the protocols which are registered, in the same format as the identify message.
+fn registered_names(protocols: &Protocols) -> Vec<String> {
+    let mut supported = Vec::new();
+    if !matches!(protocols.ping, PingMode::Disabled) {
+        supported.push(SupportProtocols::Ping);
+    }
+    if !matches!(protocols.identify, IdentifyMode::Disabled) {
+        supported.push(SupportProtocols::Identify);
+    }
+    if !matches!(protocols.discovery, DiscoveryMode::Disabled) {
+        supported.push(SupportProtocols::Discovery);
+    }
+    if !matches!(protocols.feeler, FeelerMode::Disabled) {
+        supported.push(SupportProtocols::Feeler);
+    }
+    supported.push(SupportProtocols::Transmitter);
+    supported.iter().map(SupportProtocols::name).collect()
+}
+
+fn build_meta<P>(protocol: SupportProtocols, handler: P) -> ProtocolMeta
+where
+    P: ServiceProtocol + Send + Unpin + 'static,
+{
+    protocol.build_meta_with_service_handle(move || ProtocolHandle::Callback(Box::new(handler)))
+}
+
+/// Builds the transmitter protocol with a raised frame length limit,
+/// so the crafted frames could exceed `max_frame_length` of peers.
+fn build_transmitter_meta<P>(handler: P, max_frame_length: usize) -> ProtocolMeta
+where
+    P: ServiceProtocol + Send + Unpin + 'static,
+{
+    let protocol = SupportProtocols::Transmitter;
+    let name = protocol.name();
+    MetaBuilder::new()
+        .id(protocol.protocol_id())
+        .name(move |_| name.clone())
+        .support_versions(protocol.support_versions())
+        .codec(move || {
+            Box::new(
+                length_delimited::Builder::new()
+                    .max_frame_length(max_frame_length)
+                    .new_codec(),
+            )
+        })
+        .service_handle(move || ProtocolHandle::Callback(Box::new(handler)))
+        .build()
+}
+
+impl NetworkService {
+    pub(crate) fn build_protocol_metas(
+        cfg: &Arc<NetworkConfig>,
+        pm: &Arc<PeerManager>,
+        message_router: MessageRouter,
+        chain_id: u64,
+        protocols: &Protocols,
+    ) -> Vec<ProtocolMeta> {
+        let mut protocol_metas = Vec::new();
+        match protocols.ping {
+            PingMode::Standard => {
+                let peer_manager = Arc::clone(pm);
+                let handler = PingHandler::new(cfg.ping_interval, cfg.ping_timeout, peer_manager);
+                protocol_metas.push(build_meta(SupportProtocols::Ping, handler));
+            }
+            PingMode::Disabled => {}
+            PingMode::Silent => {
+                let handler = SilentProtocol::new("ping");
+                protocol_metas.push(build_meta(SupportProtocols::Ping, handler));
+            }
+            PingMode::Stall => {
+                let handler =
+                    MisbehavingPingProtocol::new(PingMisbehavior::Stall, cfg.ping_interval);
+                protocol_metas.push(build_meta(SupportProtocols::Ping, handler));
+            }
+            PingMode::Delayed { delay } => {
+                let misbehavior = PingMisbehavior::Delayed(Duration::from_millis(delay));
+                let handler = MisbehavingPingProtocol::new(misbehavior, cfg.ping_interval);
+                protocol_metas.push(build_meta(SupportProtocols::Ping, handler));
+            }
+            PingMode::WrongNonce => {
+                let handler =
+                    MisbehavingPingProtocol::new(PingMisbehavior::WrongNonce, cfg.ping_interval);
+                protocol_metas.push(build_meta(SupportProtocols::Ping, handler));
+            }
+            PingMode::Flood { interval } => {
+                let interval = Duration::from_millis(interval);
+                let handler = MisbehavingPingProtocol::new(PingMisbehavior::Flood, interval);
+                protocol_metas.push(build_meta(SupportProtocols::Ping, handler));
+            }
+        }
+        match protocols.identify {
+            IdentifyMode::Standard => {
+                let peer_manager = Arc::clone(pm);
+                let handler = IdentifyProtocol::new(peer_manager);
+                protocol_metas.push(build_meta(SupportProtocols::Identify, handler));
+            }
+            IdentifyMode::Disabled => {}
+            IdentifyMode::Silent => {
+                let handler = SilentProtocol::new("identify");
+                protocol_metas.push(build_meta(SupportProtocols::Identify, handler));
+            }
+            IdentifyMode::Spoofed(ref spoofed) => {
+                let handler = SpoofedIdentifyProtocol::new(
+                    spoofed.clone(),
+                    chain_id,
+                    registered_names(protocols),
+                    cfg.default_listen.clone(),
+                );
+                protocol_metas.push(build_meta(SupportProtocols::Identify, handler));
+            }
+        }
+        match protocols.discovery {
+            DiscoveryMode::Standard => {
+                let peer_manager = Arc::clone(pm);
+                let address_manager = DiscoveryAddressManager::new(peer_manager);
+                let handler = DiscoveryProtocol::new(address_manager, None);
+                protocol_metas.push(build_meta(SupportProtocols::Discovery, handler));
+            }
+            DiscoveryMode::Disabled => {}
+            DiscoveryMode::Silent => {
+                let handler = SilentProtocol::new("discovery");
+                protocol_metas.push(build_meta(SupportProtocols::Discovery, handler));
+            }
+            DiscoveryMode::Poisoned(ref poisoned) => {
+                let peer_manager = Arc::clone(pm);
+                let address_manager = PoisonedAddressManager::new(
+                    DiscoveryAddressManager::new(peer_manager),
+                    poisoned.clone(),
+                );
+                let handler = DiscoveryProtocol::new(address_manager, None);
+                protocol_metas.push(build_meta(SupportProtocols::Discovery, handler));
+            }
+        }
+        match protocols.feeler {
+            FeelerMode::Standard => {
+                let peer_manager = Arc::clone(pm);
+                let handler = Feeler::new(peer_manager);
+                protocol_metas.push(build_meta(SupportProtocols::Feeler, handler));
+            }
+            FeelerMode::Disabled => {}
+            FeelerMode::Silent => {
+                let handler = SilentProtocol::new("feeler");
+                protocol_metas.push(build_meta(SupportProtocols::Feeler, handler));
+            }
+        }
+        {
+            let peer_manager = Arc::clone(pm);
+            let handler = TransmitterProtocol::new(message_router, peer_manager);
+            let max_frame_length = max_raw_frame_length(cfg);
+            protocol_metas.push(build_transmitter_meta(handler, max_frame_length));
+        }
+        protocol_metas
+    }
+
+    pub(crate) async fn dial_identify(&mut self, addr: Multiaddr) -> Result<()> {
+        let peer_id = extract_peer_id(&addr).ok_or_else(|| {
+            let errmsg = format!("failed to extract peer-id from \"{addr}\"");
+            Error::Network(errmsg)
+        })?;
+        let can_dial = self.peer_manager().with_registry_mut(|reg| {
+            !reg.peers.contains_key(&peer_id)
+                && !reg.is_feeler(&addr)
+                && reg.dialing.insert(addr.clone())
+        });
+        if can_dial {
+            let protocol = SupportProtocols::Identify.protocol_id();
+            let target = TargetProtocol::Single(protocol);
+            let _ignore = self.control().dial(addr, target).await;
+        }
+        Ok(())
+    }
+
+    pub(crate) async fn dial_feeler(&mut self, addr: Multiaddr) -> Result<()> {
+        let peer_id = extract_peer_id(&addr).ok_or_else(|| {
+            let errmsg = format!("failed to extract peer-id from \"{addr}\"");
+            Error::Network(errmsg)
+        })?;
+        let can_dial = self.peer_manager().with_registry_mut(|reg| {
+            !reg.peers.contains_key(&peer_id)
+                && !reg.dialing.contains(&addr)
+                && reg.add_feeler(addr.clone())
+        });
+        if can_dial {
+            let protocol = SupportProtocols::Identify.protocol_id();
+            let target = TargetProtocol::Single(protocol);
+            let _ignore = self.control().dial(addr, target).await;
+        }
+        Ok(())
+    }
+
+    pub(crate) async fn try_dial_feeler(&mut self) -> Result<()> {
+        let now_ms = faketime::unix_time_as_millis();
+        let attempt_peers = self.peer_manager().with_peer_store_mut(|peer_store| {
+            let paddrs = peer_store.fetch_addrs_to_feeler(10);
+            for paddr in paddrs.iter() {
+                if let Some(paddr) = peer_store.mut_addr_manager().get_mut(&paddr.addr) {
+                    paddr.mark_tried(now_ms);
+                }
+            }
+            paddrs
+        });
+        log::trace!(
+            "feeler dial count={}, attempt_peers: {:?}",
+            attempt_peers.len(),
+            attempt_peers,
+        );
+        for addr in attempt_peers.into_iter().map(|info| info.addr) {
+            self.dial_feeler(addr).await?;
+        }
+        Ok(())
+    }
+
+    pub(crate) async fn try_dial_peers(&mut self) -> Result<()> {
+        let status = self
+            .peer_manager()
+            .with_registry(|reg| reg.connection_status());
+        let count = (self.config.max_connections - self.config.inbound_conn_limit)
+            .saturating_sub(status.inbound);
+        if count == 0 {
+            self.try_identify_count = 0;
+            return Ok(());
+        }
+        self.try_identify_count += 1;
+
+        let f = |peer_store: &mut PeerStore, number: usize, now_ms: u64| -> Vec<AddrInfo> {
+            let paddrs = peer_store.fetch_addrs_to_attempt(number);
+            for paddr in paddrs.iter() {
+                if let Some(paddr) = peer_store.mut_addr_manager().get_mut(&paddr.addr) {
+                    paddr.mark_tried(now_ms);
+                }
+            }
+            paddrs
+        };
+
+        let peers: Box<dyn Iterator<Item = Multiaddr> + Send> = if self.try_identify_count > 3 {
+            self.try_identify_count = 0;
+            let bootnodes = self.peer_manager().unconnected_bootstraps();
+            let len = bootnodes.len();
+            if len < count {
+                let now_ms = faketime::unix_time_as_millis();
+                let attempt_peers = self
+                    .peer_manager()
+                    .with_peer_store_mut(|peer_store| f(peer_store, count - len, now_ms));
+
+                Box::new(
+                    attempt_peers
+                        .into_iter()
+                        .map(|info| info.addr)
+                        .chain(bootnodes.into_iter()),
+                )
+            } else {
+                Box::new(
+                    bootnodes
+                        .into_iter()
+                        .choose_multiple(&mut rand::thread_rng(), count)
+                        .into_iter(),
+                )
+            }
+        } else {
+            let now_ms = faketime::unix_time_as_millis();
+            let attempt_peers = self
+                .peer_manager()
+                .with_peer_store_mut(|peer_store| f(peer_store, count, now_ms));
+
+            log::trace!(
+                "identify dial count={}, attempt_peers: {:?}",
+                attempt_peers.len(),
+                attempt_peers,
+            );
+
+            Box::new(attempt_peers.into_iter().map(|info| info.addr))
+        };
+
+        for addr in peers {
+            self.dial_identify(addr).await?;
+        }
+        Ok(())
+    }
+}
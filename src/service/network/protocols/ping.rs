@@ -0,0 +1,151 @@
+//! A misbehaving ping protocol.
+//!
+//! The ping message is mirrored from `core-network` of Axon, which is encoded by protobuf:
+//!
+//! ```protobuf
+//! message PingMessage {
+//!     oneof payload {
+//!         uint32 ping = 1;
+//!         uint32 pong = 2;
+//!     }
+//! }
+//! ```
+
+use std::{collections::HashSet, time::Duration};
+
+use async_trait::async_trait;
+use tentacle::{
+    bytes::{Bytes, BytesMut},
+    context::{ProtocolContext, ProtocolContextMutRef},
+    traits::ServiceProtocol,
+    SessionId,
+};
+use tokio::time::sleep;
+
+use super::protobuf::{get_varint_field, put_varint_field};
+
+const PING_TAG: u64 = 1;
+const PONG_TAG: u64 = 2;
+const SEND_PING_TOKEN: u64 = 0;
+
+#[derive(Clone, Copy, Debug)]
+pub enum PingMisbehavior {
+    /// Send pings, but never answer pings.
+    Stall,
+    /// Answer pings late.
+    Delayed(Duration),
+    /// Answer pings with mismatched nonces.
+    WrongNonce,
+    /// Send pings at a high frequency, and answer pings honestly.
+    Flood,
+}
+
+pub struct MisbehavingPingProtocol {
+    misbehavior: PingMisbehavior,
+    interval: Duration,
+    sessions: HashSet<SessionId>,
+}
+
+impl MisbehavingPingProtocol {
+    /// Pings are sent to all connected peers every `interval`.
+    pub fn new(misbehavior: PingMisbehavior, interval: Duration) -> Self {
+        Self {
+            misbehavior,
+            interval,
+            sessions: HashSet::new(),
+        }
+    }
+}
+
+#[async_trait]
+impl ServiceProtocol for MisbehavingPingProtocol {
+    async fn init(&mut self, context: &mut ProtocolContext) {
+        let proto_id = context.proto_id;
+        if let Err(err) = context
+            .set_service_notify(proto_id, self.interval, SEND_PING_TOKEN)
+            .await
+        {
+            log::warn!("failed to set the notify of the ping protocol since {err}");
+        }
+    }
+
+    async fn connected(&mut self, context: ProtocolContextMutRef<'_>, _version: &str) {
+        self.sessions.insert(context.session.id);
+    }
+
+    async fn disconnected(&mut self, context: ProtocolContextMutRef<'_>) {
+        self.sessions.remove(&context.session.id);
+    }
+
+    async fn received(&mut self, context: ProtocolContextMutRef<'_>, mut data: Bytes) {
+        let session_id = context.session.id;
+        let Some((tag, nonce)) = get_varint_field(&mut data) else {
+            log::debug!("receive a malformed ping message from session {session_id}");
+            return;
+        };
+        if tag != PING_TAG {
+            log::trace!("ignore a pong message (nonce {nonce}) from session {session_id}");
+            return;
+        }
+        let pong = |nonce: u64| encode(PONG_TAG, nonce);
+        match self.misbehavior {
+            PingMisbehavior::Stall => {
+                log::trace!("ignore a ping message (nonce {nonce}) from session {session_id}");
+            }
+            PingMisbehavior::Delayed(delay) => {
+                let control = context.control().clone();
+                let proto_id = context.proto_id;
+                tokio::spawn(async move {
+                    sleep(delay).await;
+                    let _ignore = control
+                        .send_message_to(session_id, proto_id, pong(nonce))
+                        .await;
+                });
+            }
+            PingMisbehavior::WrongNonce => {
+                let wrong_nonce = u64::from((nonce as u32).wrapping_add(1));
+                let _ignore = context.send_message(pong(wrong_nonce)).await;
+            }
+            PingMisbehavior::Flood => {
+                let _ignore = context.send_message(pong(nonce)).await;
+            }
+        }
+    }
+
+    async fn notify(&mut self, context: &mut ProtocolContext, token: u64) {
+        if token != SEND_PING_TOKEN {
+            return;
+        }
+        let proto_id = context.proto_id;
+        for session_id in self.sessions.iter().copied() {
+            let ping = encode(PING_TAG, u64::from(rand::random::<u32>()));
+            if let Err(err) = context.send_message_to(session_id, proto_id, ping).await {
+                log::debug!("failed to send a ping message to session {session_id} since {err}");
+            }
+        }
+    }
+}
+
+fn encode(tag: u64, nonce: u64) -> Bytes {
+    let mut buf = BytesMut::new();
+    put_varint_field(&mut buf, tag, nonce);
+    buf.freeze()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn encode_messages() {
+        assert_eq!(&encode(PING_TAG, 1)[..], [0x08, 0x01]);
+        assert_eq!(
+            &encode(PONG_TAG, u64::from(u32::MAX))[..],
+            [0x10, 0xff, 0xff, 0xff, 0xff, 0x0f]
+        );
+
+        let mut pong = encode(PONG_TAG, 300);
+        assert_eq!(&pong[..], [0x10, 0xac, 0x02]);
+        assert_eq!(get_varint_field(&mut pong), Some((PONG_TAG, 300)));
+    }
+}
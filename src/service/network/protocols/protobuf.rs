@@ -0,0 +1,95 @@
+//! Minimal protobuf encoding, for the messages which are mirrored from `core-network` of Axon.
+
+use tentacle::bytes::{Buf as _, BufMut as _, Bytes, BytesMut};
+
+fn put_varint(buf: &mut BytesMut, mut value: u64) {
+    while value >= 0x80 {
+        buf.put_u8((value as u8 & 0x7f) | 0x80);
+        value >>= 7;
+    }
+    buf.put_u8(value as u8);
+}
+
+pub(super) fn put_varint_field(buf: &mut BytesMut, tag: u64, value: u64) {
+    put_varint(buf, tag << 3);
+    put_varint(buf, value);
+}
+
+pub(super) fn put_bytes_field(buf: &mut BytesMut, tag: u64, value: &[u8]) {
+    put_varint(buf, (tag << 3) | 2);
+    put_varint(buf, value.len() as u64);
+    buf.put_slice(value);
+}
+
+fn get_varint(buf: &mut Bytes) -> Option<u64> {
+    let mut value = 0u64;
+    for shift in (0..64).step_by(7) {
+        if !buf.has_remaining() {
+            return None;
+        }
+        let byte = buf.get_u8();
+        value |= u64::from(byte & 0x7f) << shift;
+        if byte & 0x80 == 0 {
+            return Some(value);
+        }
+    }
+    None
+}
+
+/// Reads the first field as a varint, returns its tag and its value.
+pub(super) fn get_varint_field(buf: &mut Bytes) -> Option<(u64, u64)> {
+    let key = get_varint(buf)?;
+    if key & 0x7 != 0 {
+        return None;
+    }
+    let value = get_varint(buf)?;
+    Some((key >> 3, value))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn put_fields() {
+        let mut buf = BytesMut::new();
+        put_varint_field(&mut buf, 1, 150);
+        assert_eq!(&buf[..], [0x08, 0x96, 0x01]);
+
+        let mut buf = BytesMut::new();
+        put_bytes_field(&mut buf, 2, b"testing");
+        assert_eq!(
+            &buf[..],
+            [0x12, 0x07, 0x74, 0x65, 0x73, 0x74, 0x69, 0x6e, 0x67]
+        );
+
+        let mut buf = BytesMut::new();
+        put_varint_field(&mut buf, 3, u64::MAX);
+        let mut expected = vec![0x18];
+        expected.extend([0xff; 9]);
+        expected.push(0x01);
+        assert_eq!(&buf[..], expected);
+    }
+
+    #[test]
+    fn get_fields() {
+        let mut buf = Bytes::from_static(&[0x08, 0x96, 0x01, 0x10, 0x00]);
+        assert_eq!(get_varint_field(&mut buf), Some((1, 150)));
+        assert_eq!(get_varint_field(&mut buf), Some((2, 0)));
+        assert_eq!(get_varint_field(&mut buf), None);
+
+        // length-delimited field
+        let mut buf = Bytes::from_static(&[0x12, 0x01, 0x00]);
+        assert_eq!(get_varint_field(&mut buf), None);
+
+        // truncated varint
+        let mut buf = Bytes::from_static(&[0x08, 0x96]);
+        assert_eq!(get_varint_field(&mut buf), None);
+
+        // too long varint
+        let mut buf = Bytes::from_static(&[
+            0x08, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+        ]);
+        assert_eq!(get_varint_field(&mut buf), None);
+    }
+}
@@ -0,0 +1,31 @@
+use async_trait::async_trait;
+use tentacle::{
+    bytes::Bytes,
+    context::{ProtocolContext, ProtocolContextMutRef},
+    traits::ServiceProtocol,
+};
+
+/// A protocol handler which accepts the protocol but never sends anything.
+pub struct SilentProtocol {
+    name: &'static str,
+}
+
+impl SilentProtocol {
+    pub fn new(name: &'static str) -> Self {
+        Self { name }
+    }
+}
+
+#[async_trait]
+impl ServiceProtocol for SilentProtocol {
+    async fn init(&mut self, _context: &mut ProtocolContext) {}
+
+    async fn received(&mut self, context: ProtocolContextMutRef<'_>, data: Bytes) {
+        log::trace!(
+            "{} protocol ignores {} bytes from session {}",
+            self.name,
+            data.len(),
+            context.session.id
+        );
+    }
+}
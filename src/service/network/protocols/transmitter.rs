@@ -0,0 +1,103 @@
+//! Raw frames of the transmitter protocol, which bypass the message codec.
+//!
+//! The network message is mirrored from `core-network` of Axon, which is encoded by protobuf:
+//!
+//! ```protobuf
+//! message NetworkMessage {
+//!     map<string, bytes> headers = 1;
+//!     string url = 2;
+//!     bytes content = 3;
+//! }
+//! ```
+//!
+//! A frame is a flag byte followed by the network message,
+//! the highest bit of the flag byte means whether the message is compressed by snappy.
+
+use std::collections::HashMap;
+
+use rand::RngCore as _;
+use serde::Deserialize;
+use tentacle::bytes::{BufMut as _, Bytes, BytesMut};
+
+use crate::{
+    axon::{core::network::NetworkConfig, protocol::types::Hex},
+    result::{Error, Result},
+};
+
+use super::protobuf::put_bytes_field;
+
+const COMPRESS_FLAG: u8 = 0b1000_0000;
+/// How many bytes the frames sent by the injector could exceed `max_frame_length`.
+const OVERSIZED_MARGIN: usize = 4 * 1024 * 1024;
+
+/// The frame length limit of the transmitter protocol of the injector.
+pub fn max_raw_frame_length(config: &NetworkConfig) -> usize {
+    config.max_frame_length.saturating_add(OVERSIZED_MARGIN)
+}
+
+/// A frame to be crafted, nothing is checked.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct TransmitterFrame {
+    /// Not required to be a registered endpoint, or even a valid one.
+    pub endpoint: String,
+    pub content: Hex,
+    #[serde(default)]
+    pub headers: HashMap<String, Hex>,
+    /// Whether the message is compressed actually.
+    #[serde(default)]
+    pub compress: bool,
+    /// Whether the flag byte claims the message is compressed, same as `compress` if it's omitted.
+    pub compressed_flag: Option<bool>,
+    /// Count of random bytes appended to the frame, for example, to exceed `max_frame_length`.
+    #[serde(default)]
+    pub padding: usize,
+}
+
+impl TransmitterFrame {
+    /// Encodes the frame, the `padding` should not exceed `max_length`.
+    pub fn encode(&self, max_length: usize) -> Result<Bytes> {
+        if self.padding > max_length {
+            let errmsg = format!(
+                "the padding {} of the frame exceeds the limit {max_length}",
+                self.padding
+            );
+            return Err(Error::Network(errmsg));
+        }
+        let mut msg = BytesMut::new();
+        for (key, value) in &self.headers {
+            let mut entry = BytesMut::new();
+            put_bytes_field(&mut entry, 1, key.as_bytes());
+            put_bytes_field(&mut entry, 2, &value.as_bytes());
+            put_bytes_field(&mut msg, 1, &entry);
+        }
+        put_bytes_field(&mut msg, 2, self.endpoint.as_bytes());
+        put_bytes_field(&mut msg, 3, &self.content.as_bytes());
+
+        let payload = if self.compress {
+            snap::raw::Encoder::new()
+                .compress_vec(&msg)
+                .map_err(|err| {
+                    let errmsg = format!("failed to compress the frame since {err}");
+                    Error::Network(errmsg)
+                })?
+        } else {
+            msg.to_vec()
+        };
+        let flag = if self.compressed_flag.unwrap_or(self.compress) {
+            COMPRESS_FLAG
+        } else {
+            0
+        };
+
+        let mut frame = BytesMut::with_capacity(1 + payload.len() + self.padding);
+        frame.put_u8(flag);
+        frame.put_slice(&payload);
+        if self.padding > 0 {
+            let mut padding = vec![0u8; self.padding];
+            rand::thread_rng().fill_bytes(&mut padding);
+            frame.put_slice(&padding);
+        }
+        Ok(frame.freeze())
+    }
+}
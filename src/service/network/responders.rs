@@ -0,0 +1,258 @@
+use std::{iter, sync::Arc, time::Duration};
+
+use async_trait::async_trait;
+use rand::RngCore as _;
+use tokio::time::sleep;
+
+use crate::{
+    axon::{
+        core::network::{endpoint::Endpoint, NetworkRpc},
+        protocol::{
+            traits::{Context, MessageCodec, MessageHandler, Priority, Rpc, TrustFeedback},
+            types::{Bytes, SignedTransaction, H256},
+            ProtocolResult,
+        },
+        services::{
+            endpoints::jsonrpc::{
+                RPC_RESP_PULL_TXS, RPC_RESP_PULL_TXS_SYNC, RPC_RESP_SYNC_PULL_BLOCK,
+                RPC_RESP_SYNC_PULL_PROOF, RPC_RESP_SYNC_PULL_TXS,
+            },
+            messages::{
+                mempool::{MsgPullTxs, MsgPushTxs},
+                storage::{Block, BlockNumber, FixedSignedTxs, Header, Proof, PullTxsRequest},
+                RawMessage,
+            },
+        },
+    },
+    capture::Recorder,
+    configs::{
+        feedback::Feedback,
+        responders::{Behavior, ResponderConfig},
+    },
+    result::Error,
+    service::txpool::TxPool,
+};
+
+use super::endpoints::record_message;
+
+/// Builds the response of a request.
+pub(crate) trait Respond: Send + Sync + 'static {
+    type Request: MessageCodec + Sync;
+
+    fn response_endpoint(request: &Self::Request) -> &'static str;
+
+    fn respond(&self, request: &Self::Request, behavior: &Behavior) -> ProtocolResult<Bytes>;
+}
+
+pub struct Responder<R> {
+    endpoint: Endpoint,
+    rpc: NetworkRpc,
+    config: ResponderConfig,
+    recorder: Option<Arc<Recorder>>,
+    feedback: Feedback,
+    inner: R,
+}
+
+#[async_trait]
+impl<R: Respond> MessageHandler for Responder<R> {
+    type Message = R::Request;
+    async fn process(&self, ctx: Context, mut msg: Self::Message) -> TrustFeedback {
+        if let Some(recorder) = self.recorder.as_ref() {
+            record_message(recorder, &ctx, &self.endpoint, &mut msg);
+        }
+        let ret = match &self.config.behavior {
+            Behavior::Ignore => {
+                log::trace!("ignore a request on endpoint {}: {msg:?}", self.endpoint);
+                return self.feedback.clone().into();
+            }
+            Behavior::Garbage { size } => Ok(random_bytes(*size)),
+            Behavior::Error { message } => Err(Error::Responder(message.clone()).into()),
+            behavior => self.inner.respond(&msg, behavior),
+        };
+        if self.config.delay > 0 {
+            sleep(Duration::from_millis(self.config.delay)).await;
+        }
+        let _ignore = self
+            .rpc
+            .response(
+                ctx,
+                R::response_endpoint(&msg),
+                ret.map(RawMessage),
+                Priority::High,
+            )
+            .await
+            .map_err(|err| {
+                log::warn!(
+                    "failed to respond a request on endpoint {} since {err}",
+                    self.endpoint
+                )
+            });
+        self.feedback.clone().into()
+    }
+}
+
+impl<R: Respond> Responder<R> {
+    pub fn new(
+        endpoint: Endpoint,
+        rpc: NetworkRpc,
+        config: ResponderConfig,
+        recorder: Option<Arc<Recorder>>,
+        feedback: Feedback,
+        inner: R,
+    ) -> Self {
+        Self {
+            endpoint,
+            rpc,
+            config,
+            recorder,
+            feedback,
+            inner,
+        }
+    }
+}
+
+pub(crate) struct SyncPullBlock {
+    chain_id: u64,
+}
+
+impl SyncPullBlock {
+    pub(crate) fn new(chain_id: u64) -> Self {
+        Self { chain_id }
+    }
+}
+
+impl Respond for SyncPullBlock {
+    type Request = BlockNumber;
+
+    fn response_endpoint(_: &Self::Request) -> &'static str {
+        RPC_RESP_SYNC_PULL_BLOCK
+    }
+
+    fn respond(&self, number: &BlockNumber, behavior: &Behavior) -> ProtocolResult<Bytes> {
+        let number = shift_height(*number, behavior);
+        let proof = synthetic_proof(number.saturating_sub(1), behavior);
+        let header = Header {
+            number,
+            chain_id: self.chain_id,
+            proof,
+            ..Default::default()
+        };
+        let mut block = Block {
+            header,
+            tx_hashes: Vec::new(),
+        };
+        block.encode_msg()
+    }
+}
+
+pub(crate) struct SyncPullProof;
+
+impl Respond for SyncPullProof {
+    type Request = BlockNumber;
+
+    fn response_endpoint(_: &Self::Request) -> &'static str {
+        RPC_RESP_SYNC_PULL_PROOF
+    }
+
+    fn respond(&self, number: &BlockNumber, behavior: &Behavior) -> ProtocolResult<Bytes> {
+        let number = shift_height(*number, behavior);
+        let mut proof = synthetic_proof(number, behavior);
+        proof.encode_msg()
+    }
+}
+
+pub(crate) struct SyncPullTxs {
+    txpool: Arc<TxPool>,
+}
+
+impl SyncPullTxs {
+    pub(crate) fn new(txpool: Arc<TxPool>) -> Self {
+        Self { txpool }
+    }
+}
+
+impl Respond for SyncPullTxs {
+    type Request = PullTxsRequest;
+
+    fn response_endpoint(_: &Self::Request) -> &'static str {
+        RPC_RESP_SYNC_PULL_TXS
+    }
+
+    fn respond(&self, request: &PullTxsRequest, behavior: &Behavior) -> ProtocolResult<Bytes> {
+        let inner = select_txs(&self.txpool, &request.inner, behavior);
+        let mut txs = FixedSignedTxs { inner };
+        txs.encode_msg()
+    }
+}
+
+pub(crate) struct PullTxs {
+    txpool: Arc<TxPool>,
+}
+
+impl PullTxs {
+    pub(crate) fn new(txpool: Arc<TxPool>) -> Self {
+        Self { txpool }
+    }
+}
+
+impl Respond for PullTxs {
+    type Request = MsgPullTxs;
+
+    fn response_endpoint(request: &MsgPullTxs) -> &'static str {
+        if request.height.is_some() {
+            RPC_RESP_PULL_TXS_SYNC
+        } else {
+            RPC_RESP_PULL_TXS
+        }
+    }
+
+    fn respond(&self, request: &MsgPullTxs, behavior: &Behavior) -> ProtocolResult<Bytes> {
+        let sig_txs = select_txs(&self.txpool, &request.hashes, behavior);
+        let mut txs = MsgPushTxs { sig_txs };
+        txs.encode_msg()
+    }
+}
+
+fn select_txs(txpool: &TxPool, hashes: &[H256], behavior: &Behavior) -> Vec<SignedTransaction> {
+    match behavior {
+        Behavior::Truncated { keep } => hashes
+            .iter()
+            .filter_map(|hash| txpool.get(hash))
+            .take(*keep)
+            .collect(),
+        Behavior::Others => txpool.others(hashes, hashes.len()),
+        Behavior::Duplicated { times } => hashes
+            .iter()
+            .filter_map(|hash| txpool.get(hash))
+            .flat_map(|stx| iter::repeat(stx).take(*times))
+            .collect(),
+        _ => hashes.iter().filter_map(|hash| txpool.get(hash)).collect(),
+    }
+}
+
+fn shift_height(number: BlockNumber, behavior: &Behavior) -> BlockNumber {
+    match behavior {
+        Behavior::WrongHeight { offset } => number.saturating_add_signed(*offset),
+        _ => number,
+    }
+}
+
+fn synthetic_proof(number: BlockNumber, behavior: &Behavior) -> Proof {
+    let (signature, bitmap) = match behavior {
+        Behavior::InvalidProof => (random_bytes(96), random_bytes(8)),
+        _ => (Bytes::new(), Bytes::new()),
+    };
+    Proof {
+        number,
+        round: 0,
+        block_hash: H256::default(),
+        signature,
+        bitmap,
+    }
+}
+
+fn random_bytes(size: usize) -> Bytes {
+    let mut bytes = vec![0u8; size];
+    rand::thread_rng().fill_bytes(&mut bytes);
+    Bytes::from(bytes)
+}
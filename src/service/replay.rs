@@ -0,0 +1,91 @@
+use std::time::Duration;
+
+use tokio::time::{sleep_until, Instant};
+
+use crate::{
+    axon::{
+        core::network::NetworkGossip,
+        protocol::traits::{Context, Gossip, Priority},
+        services::messages::RawMessage,
+    },
+    capture::Record,
+    configs::serve::Config,
+    result::Result,
+};
+
+use super::{network::NetworkService, run_with_network};
+
+const GOSSIP_ENDPOINT_PREFIX: &str = "/gossip/";
+
+pub struct ReplayService {
+    network: NetworkService,
+    records: Vec<Record>,
+    speed: f64,
+}
+
+impl ReplayService {
+    pub fn new(config: &Config, records: Vec<Record>, speed: f64) -> Result<Self> {
+        let network = NetworkService::new(config)?;
+        let service = Self {
+            network,
+            records,
+            speed,
+        };
+        Ok(service)
+    }
+
+    pub fn run(self) -> Result<()> {
+        let Self {
+            network,
+            records,
+            speed,
+        } = self;
+        run_with_network(network, |gossip| async move {
+            replay_records(&gossip, records, speed).await;
+            Ok(())
+        })
+    }
+}
+
+async fn replay_records(gossip: &NetworkGossip, records: Vec<Record>, speed: f64) {
+    let Some(first_timestamp) = records.first().map(|record| record.timestamp) else {
+        log::warn!("no records to replay");
+        return;
+    };
+    let total = records.len();
+    let mut skipped = 0;
+    let start = Instant::now();
+    for (index, record) in records.into_iter().enumerate() {
+        // RPC requests and responses can't be broadcasted, and make no sense without sessions.
+        if !record.endpoint.starts_with(GOSSIP_ENDPOINT_PREFIX) {
+            log::debug!(
+                "skip record {}/{total} on non-gossip endpoint {}",
+                index + 1,
+                record.endpoint
+            );
+            skipped += 1;
+            continue;
+        }
+        let offset_ms = record.timestamp.saturating_sub(first_timestamp) as f64 / speed;
+        sleep_until(start + Duration::from_secs_f64(offset_ms / 1000.0)).await;
+        log::debug!(
+            "replay record {}/{total} on endpoint {}",
+            index + 1,
+            record.endpoint
+        );
+        let msg = RawMessage(record.bytes.as_bytes());
+        let _ignore = gossip
+            .broadcast(Context::new(), &record.endpoint, msg, Priority::High)
+            .await
+            .map_err(|err| {
+                log::warn!(
+                    "failed to replay a record on endpoint {} since {err}",
+                    record.endpoint
+                )
+            });
+    }
+    log::info!(
+        "{} records are replayed, {skipped} records on non-gossip endpoints are skipped",
+        total - skipped
+    );
+}
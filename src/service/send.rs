@@ -0,0 +1,44 @@
+use crate::{
+    axon::protocol::traits::{Context, Gossip, MessageCodec, Priority},
+    configs::serve::Config,
+    result::Result,
+};
+
+use super::{network::NetworkService, run_with_network};
+
+/// Broadcasts a message once, then stops.
+pub struct SendService<M> {
+    network: NetworkService,
+    endpoint: &'static str,
+    msg: M,
+}
+
+impl<M: MessageCodec> SendService<M> {
+    pub fn new(config: &Config, endpoint: &'static str, msg: M) -> Result<Self> {
+        let network = NetworkService::new(config)?;
+        let service = Self {
+            network,
+            endpoint,
+            msg,
+        };
+        Ok(service)
+    }
+
+    pub fn run(self) -> Result<()> {
+        let Self {
+            network,
+            endpoint,
+            msg,
+        } = self;
+        run_with_network(network, |gossip| async move {
+            let ctx = Context::new();
+            let pri = Priority::High;
+
+            log::debug!("gossip broadcast ...");
+            gossip.broadcast(ctx, endpoint, msg, pri).await?;
+            log::info!("A message is sent to {endpoint}");
+
+            Ok(())
+        })
+    }
+}
@@ -0,0 +1,139 @@
+use std::{
+    collections::{HashMap, VecDeque},
+    sync::{PoisonError, RwLock},
+};
+
+use crate::axon::protocol::types::{SignedTransaction, H256};
+
+/// Transactions which are known by the injector, keyed by their hashes.
+///
+/// At most `capacity` transactions are kept, the earliest inserted ones are evicted first.
+pub struct TxPool {
+    capacity: usize,
+    inner: RwLock<Inner>,
+}
+
+#[derive(Default)]
+struct Inner {
+    txs: HashMap<H256, SignedTransaction>,
+    /// Hashes in insertion order.
+    order: VecDeque<H256>,
+}
+
+impl TxPool {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            inner: RwLock::default(),
+        }
+    }
+
+    pub fn insert(&self, stx: SignedTransaction) {
+        let mut inner = self.inner.write().unwrap_or_else(PoisonError::into_inner);
+        let hash = stx.transaction.hash;
+        if inner.txs.insert(hash, stx).is_none() {
+            inner.order.push_back(hash);
+        }
+        while inner.txs.len() > self.capacity {
+            let Some(evicted) = inner.order.pop_front() else {
+                break;
+            };
+            inner.txs.remove(&evicted);
+        }
+    }
+
+    pub fn get(&self, hash: &H256) -> Option<SignedTransaction> {
+        let inner = self.inner.read().unwrap_or_else(PoisonError::into_inner);
+        inner.txs.get(hash).cloned()
+    }
+
+    pub fn hashes(&self) -> Vec<H256> {
+        let inner = self.inner.read().unwrap_or_else(PoisonError::into_inner);
+        inner.order.iter().copied().collect()
+    }
+
+    pub fn remove(&self, hash: &H256) -> Option<SignedTransaction> {
+        let mut inner = self.inner.write().unwrap_or_else(PoisonError::into_inner);
+        let stx = inner.txs.remove(hash)?;
+        inner.order.retain(|h| h != hash);
+        Some(stx)
+    }
+
+    /// Removes all transactions, returns how many transactions are removed.
+    pub fn clear(&self) -> usize {
+        let mut inner = self.inner.write().unwrap_or_else(PoisonError::into_inner);
+        let count = inner.txs.len();
+        inner.txs.clear();
+        inner.order.clear();
+        count
+    }
+
+    /// Returns at most `count` transactions whose hashes are not in `excluded`.
+    pub fn others(&self, excluded: &[H256], count: usize) -> Vec<SignedTransaction> {
+        let inner = self.inner.read().unwrap_or_else(PoisonError::into_inner);
+        inner
+            .txs
+            .iter()
+            .filter(|(hash, _)| !excluded.contains(hash))
+            .map(|(_, stx)| stx.clone())
+            .take(count)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::axon::protocol::types::{
+        Bytes, LegacyTransaction, TransactionAction, UnsignedTransaction, UnverifiedTransaction,
+        H160, U256,
+    };
+
+    fn stx(n: u64) -> SignedTransaction {
+        let unsigned = UnsignedTransaction::Legacy(LegacyTransaction {
+            nonce: U256::from(n),
+            gas_price: U256::zero(),
+            gas_limit: U256::zero(),
+            action: TransactionAction::Create,
+            value: U256::zero(),
+            data: Bytes::new(),
+        });
+        let transaction = UnverifiedTransaction {
+            unsigned,
+            signature: None,
+            chain_id: None,
+            hash: H256::from_low_u64_be(n),
+        };
+        SignedTransaction {
+            transaction,
+            sender: H160::default(),
+            public: None,
+        }
+    }
+
+    fn hash(n: u64) -> H256 {
+        H256::from_low_u64_be(n)
+    }
+
+    #[test]
+    fn evict_earliest_inserted() {
+        let txpool = TxPool::new(2);
+        txpool.insert(stx(1));
+        txpool.insert(stx(2));
+        // inserting a known transaction again doesn't refresh it
+        txpool.insert(stx(1));
+        assert_eq!(txpool.hashes(), vec![hash(1), hash(2)]);
+
+        txpool.insert(stx(3));
+        assert_eq!(txpool.hashes(), vec![hash(2), hash(3)]);
+        assert!(txpool.get(&hash(1)).is_none());
+        assert!(txpool.get(&hash(3)).is_some());
+
+        assert!(txpool.remove(&hash(2)).is_some());
+        txpool.insert(stx(4));
+        assert_eq!(txpool.hashes(), vec![hash(3), hash(4)]);
+
+        assert_eq!(txpool.clear(), 2);
+        assert!(txpool.hashes().is_empty());
+    }
+}